@@ -82,6 +82,26 @@ pub trait ToArrow {
             .map_err(ArrayError::to_arrow)
     }
 
+    /// Exports a `DataChunk` through the [Arrow C Data
+    /// Interface](https://arrow.apache.org/docs/format/CDataInterface.html) for zero-copy
+    /// interop with other Arrow implementations (e.g. handing batches to a Python/C++ host
+    /// process without a serialization round-trip).
+    ///
+    /// This lowers the chunk to a `RecordBatch` via [`ToArrow::to_record_batch`] first, then
+    /// hands the resulting struct array to arrow-rs's own FFI exporter.
+    fn to_ffi(
+        &self,
+        schema: arrow_schema::SchemaRef,
+        chunk: &DataChunk,
+    ) -> Result<(arrow_array::ffi::FFI_ArrowArray, arrow_array::ffi::FFI_ArrowSchema), ArrayError>
+    {
+        use arrow_array::Array;
+
+        let batch = self.to_record_batch(schema, chunk)?;
+        let struct_array: arrow_array::StructArray = batch.into();
+        arrow_array::ffi::to_ffi(&struct_array.to_data()).map_err(ArrayError::to_arrow)
+    }
+
     /// Converts RisingWave array to Arrow array.
     fn to_array(
         &self,
@@ -148,7 +168,13 @@ pub trait ToArrow {
 
     #[inline]
     fn utf8_to_arrow(&self, array: &Utf8Array) -> Result<arrow_array::ArrayRef, ArrayError> {
-        Ok(Arc::new(arrow_array::StringArray::from(array)))
+        if self.prefer_string_view() {
+            Ok(Arc::new(
+                array.iter().collect::<arrow_array::StringViewArray>(),
+            ))
+        } else {
+            Ok(Arc::new(arrow_array::StringArray::from(array)))
+        }
     }
 
     #[inline]
@@ -177,10 +203,20 @@ pub trait ToArrow {
         array: &TimestamptzArray,
     ) -> Result<arrow_array::ArrayRef, ArrayError> {
         Ok(Arc::new(
-            arrow_array::TimestampMicrosecondArray::from(array).with_timezone_utc(),
+            arrow_array::TimestampMicrosecondArray::from(array)
+                .with_timezone(self.timestamptz_timezone()),
         ))
     }
 
+    /// The Arrow timezone string attached to `Timestamptz` fields/arrays produced by this
+    /// converter. Defaults to UTC; override to carry the session/source zone name (e.g.
+    /// `"America/New_York"`) through instead. The underlying microsecond instant is always UTC
+    /// regardless of this value -- only the attached zone metadata differs.
+    #[inline]
+    fn timestamptz_timezone(&self) -> Arc<str> {
+        Arc::from("+00:00")
+    }
+
     #[inline]
     fn time_to_arrow(&self, array: &TimeArray) -> Result<arrow_array::ArrayRef, ArrayError> {
         Ok(Arc::new(arrow_array::Time64MicrosecondArray::from(array)))
@@ -198,17 +234,61 @@ pub trait ToArrow {
 
     #[inline]
     fn bytea_to_arrow(&self, array: &BytesArray) -> Result<arrow_array::ArrayRef, ArrayError> {
-        Ok(Arc::new(arrow_array::BinaryArray::from(array)))
+        if self.prefer_string_view() {
+            Ok(Arc::new(
+                array.iter().collect::<arrow_array::BinaryViewArray>(),
+            ))
+        } else {
+            Ok(Arc::new(arrow_array::BinaryArray::from(array)))
+        }
     }
 
-    // Decimal values are stored as ASCII text representation in a string array.
+    // Decimal values are stored as ASCII text representation in a string array, unless the
+    // target schema pins down a fixed `Decimal128`/`Decimal256` precision and scale, in which
+    // case we emit native Arrow decimals instead.
     #[inline]
     fn decimal_to_arrow(
         &self,
-        _data_type: &arrow_schema::DataType,
+        data_type: &arrow_schema::DataType,
         array: &DecimalArray,
     ) -> Result<arrow_array::ArrayRef, ArrayError> {
-        Ok(Arc::new(arrow_array::StringArray::from(array)))
+        match data_type {
+            arrow_schema::DataType::Decimal128(precision, scale) => {
+                let mut builder = arrow_array::builder::Decimal128Builder::with_capacity(
+                    array.len(),
+                )
+                .with_precision_and_scale(*precision, *scale)
+                .map_err(ArrayError::to_arrow)?;
+                for value in array.iter() {
+                    match value
+                        .map(|d| decimal_to_fixed_i128(d, *precision, *scale))
+                        .transpose()?
+                    {
+                        Some(v) => builder.append_value(v),
+                        None => builder.append_null(),
+                    }
+                }
+                Ok(Arc::new(builder.finish()))
+            }
+            arrow_schema::DataType::Decimal256(precision, scale) => {
+                let mut builder = arrow_array::builder::Decimal256Builder::with_capacity(
+                    array.len(),
+                )
+                .with_precision_and_scale(*precision, *scale)
+                .map_err(ArrayError::to_arrow)?;
+                for value in array.iter() {
+                    match value
+                        .map(|d| decimal_to_fixed_i128(d, *precision, *scale))
+                        .transpose()?
+                    {
+                        Some(v) => builder.append_value(arrow_buffer::i256::from_i128(v)),
+                        None => builder.append_null(),
+                    }
+                }
+                Ok(Arc::new(builder.finish()))
+            }
+            _ => Ok(Arc::new(arrow_array::StringArray::from(array))),
+        }
     }
 
     // JSON values are stored as text representation in a string array.
@@ -228,18 +308,86 @@ pub trait ToArrow {
         data_type: &arrow_schema::DataType,
         array: &ListArray,
     ) -> Result<arrow_array::ArrayRef, ArrayError> {
-        let arrow_schema::DataType::List(field) = data_type else {
-            return Err(ArrayError::to_arrow("Invalid list type"));
-        };
-        let values = self.to_array(field.data_type(), array.values())?;
-        let offsets = OffsetBuffer::new(array.offsets().iter().map(|&o| o as i32).collect());
-        let nulls = (!array.null_bitmap().all()).then(|| array.null_bitmap().into());
-        Ok(Arc::new(arrow_array::ListArray::new(
-            field.clone(),
-            offsets,
-            values,
-            nulls,
-        )))
+        match data_type {
+            arrow_schema::DataType::List(field) => {
+                let values = self.to_array(field.data_type(), array.values())?;
+                let offsets: Vec<i32> = array
+                    .offsets()
+                    .iter()
+                    .map(|&o| {
+                        i32::try_from(o).map_err(|_| {
+                            ArrayError::to_arrow(
+                                "list offset overflows i32, use a `LargeList` target type instead",
+                            )
+                        })
+                    })
+                    .try_collect()?;
+                let nulls = (!array.null_bitmap().all()).then(|| array.null_bitmap().into());
+                Ok(Arc::new(arrow_array::ListArray::new(
+                    field.clone(),
+                    OffsetBuffer::new(offsets.into()),
+                    values,
+                    nulls,
+                )))
+            }
+            // Embedding/vector columns: every element must share the declared fixed length `n`,
+            // since `FixedSizeListArray` has no per-element offsets of its own.
+            arrow_schema::DataType::FixedSizeList(field, n) => {
+                let n = *n as u32;
+                for (i, (&start, &end)) in array.offsets().iter().tuple_windows().enumerate() {
+                    let len = end - start;
+                    if len != n {
+                        return Err(ArrayError::to_arrow(format!(
+                            "list at index {i} has length {len}, but the target `FixedSizeList` requires a fixed length of {n}"
+                        )));
+                    }
+                }
+                let values = self.to_array(field.data_type(), array.values())?;
+                let nulls = (!array.null_bitmap().all()).then(|| array.null_bitmap().into());
+                Ok(Arc::new(arrow_array::FixedSizeListArray::new(
+                    field.clone(),
+                    n as i32,
+                    values,
+                    nulls,
+                )))
+            }
+            arrow_schema::DataType::LargeList(field) => {
+                let values = self.to_array(field.data_type(), array.values())?;
+                let offsets = OffsetBuffer::new(array.offsets().iter().map(|&o| o as i64).collect());
+                let nulls = (!array.null_bitmap().all()).then(|| array.null_bitmap().into());
+                Ok(Arc::new(arrow_array::LargeListArray::new(
+                    field.clone(),
+                    offsets,
+                    values,
+                    nulls,
+                )))
+            }
+            // A RisingWave map is represented internally as `List<Struct<key, value>>`; emit a
+            // canonical Arrow `Map` when the target schema asks for one.
+            arrow_schema::DataType::Map(entries_field, sorted) => {
+                let ArrayImpl::Struct(entries) = array.values() else {
+                    return Err(ArrayError::to_arrow(
+                        "map value array must be a struct of (key, value)",
+                    ));
+                };
+                let entries_array = self.struct_to_arrow(entries_field.data_type(), entries)?;
+                let entries_array = entries_array
+                    .as_any()
+                    .downcast_ref::<arrow_array::StructArray>()
+                    .ok_or_else(|| ArrayError::to_arrow("map entries must convert to a struct array"))?
+                    .clone();
+                let offsets = OffsetBuffer::new(array.offsets().iter().map(|&o| o as i32).collect());
+                let nulls = (!array.null_bitmap().all()).then(|| array.null_bitmap().into());
+                Ok(Arc::new(arrow_array::MapArray::new(
+                    entries_field.clone(),
+                    offsets,
+                    entries_array,
+                    nulls,
+                    *sorted,
+                )))
+            }
+            _ => Err(ArrayError::to_arrow("Invalid list type")),
+        }
     }
 
     #[inline]
@@ -350,7 +498,7 @@ pub trait ToArrow {
     fn timestamptz_type_to_arrow(&self) -> arrow_schema::DataType {
         arrow_schema::DataType::Timestamp(
             arrow_schema::TimeUnit::Microsecond,
-            Some("+00:00".into()),
+            Some(self.timestamptz_timezone()),
         )
     }
 
@@ -361,7 +509,25 @@ pub trait ToArrow {
 
     #[inline]
     fn varchar_type_to_arrow(&self) -> arrow_schema::DataType {
-        arrow_schema::DataType::Utf8
+        if self.prefer_string_view() {
+            arrow_schema::DataType::Utf8View
+        } else {
+            arrow_schema::DataType::Utf8
+        }
+    }
+
+    /// Whether `Varchar`/`Bytea` columns should be emitted as Arrow's `Utf8View`/`BinaryView`
+    /// layout instead of the classic `Utf8`/`Binary` layout. Defaults to `false` so existing
+    /// consumers keep seeing the layout they already expect; override to hand modern readers
+    /// (e.g. a Parquet writer targeting the view encoding) the view layout directly instead of
+    /// making them cast it up themselves.
+    ///
+    /// This is the `ToArrow` (emit) direction; the `FromArrow` (accept) direction -- reading a
+    /// `Utf8View`/`BinaryView` array a producer handed us, via `from_utf8_view_array` /
+    /// `from_binary_view_array` below -- is unconditional and doesn't need an opt-in here.
+    #[inline]
+    fn prefer_string_view(&self) -> bool {
+        false
     }
 
     #[inline]
@@ -372,7 +538,11 @@ pub trait ToArrow {
 
     #[inline]
     fn bytea_type_to_arrow(&self) -> arrow_schema::DataType {
-        arrow_schema::DataType::Binary
+        if self.prefer_string_view() {
+            arrow_schema::DataType::BinaryView
+        } else {
+            arrow_schema::DataType::Binary
+        }
     }
 
     #[inline]
@@ -396,6 +566,38 @@ pub trait ToArrow {
         )))
     }
 
+    /// Like [`ToArrow::list_type_to_arrow`], but produces a `LargeList` (i64 offsets) for lists
+    /// whose combined child length may exceed `i32::MAX`.
+    #[inline]
+    fn large_list_type_to_arrow(
+        &self,
+        elem_type: &DataType,
+    ) -> Result<arrow_schema::DataType, ArrayError> {
+        Ok(arrow_schema::DataType::LargeList(Arc::new(
+            self.to_arrow_field("item", elem_type)?,
+        )))
+    }
+
+    /// Builds the canonical Arrow `Map` type for a RisingWave `List<Struct<key, value>>`,
+    /// naming the entries/key/value fields the way arrow-rs does by default.
+    #[inline]
+    fn map_type_to_arrow(
+        &self,
+        key_type: &DataType,
+        value_type: &DataType,
+    ) -> Result<arrow_schema::DataType, ArrayError> {
+        let entries = arrow_schema::Fields::from(vec![
+            self.to_arrow_field("key", key_type)?,
+            self.to_arrow_field("value", value_type)?,
+        ]);
+        let entries_field = arrow_schema::Field::new(
+            "entries",
+            arrow_schema::DataType::Struct(entries),
+            false,
+        );
+        Ok(arrow_schema::DataType::Map(Arc::new(entries_field), false))
+    }
+
     #[inline]
     fn struct_type_to_arrow(
         &self,
@@ -423,6 +625,25 @@ pub trait FromArrow {
         Ok(DataChunk::new(columns, batch.num_rows()))
     }
 
+    /// Imports a `DataChunk` from the Arrow C Data Interface, the inverse of
+    /// [`ToArrow::to_ffi`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `array` and `schema` were produced by a valid Arrow C Data
+    /// Interface exporter and have not already been consumed.
+    unsafe fn from_ffi(
+        &self,
+        array: arrow_array::ffi::FFI_ArrowArray,
+        schema: arrow_array::ffi::FFI_ArrowSchema,
+    ) -> Result<DataChunk, ArrayError> {
+        let array_data =
+            unsafe { arrow_array::ffi::from_ffi(array, &schema) }.map_err(ArrayError::from_arrow)?;
+        let struct_array = arrow_array::StructArray::from(array_data);
+        let batch: arrow_array::RecordBatch = struct_array.into();
+        self.from_record_batch(&batch)
+    }
+
     /// Converts Arrow `Fields` to RisingWave `StructType`.
     fn from_fields(&self, fields: &arrow_schema::Fields) -> Result<StructType, ArrayError> {
         Ok(StructType::new(
@@ -453,17 +674,29 @@ pub trait FromArrow {
             Float64 => DataType::Float64,
             Decimal128(_, _) => DataType::Decimal,
             Decimal256(_, _) => DataType::Int256,
-            Date32 => DataType::Date,
-            Time64(Microsecond) => DataType::Time,
-            Timestamp(Microsecond, None) => DataType::Timestamp,
-            Timestamp(Microsecond, Some(_)) => DataType::Timestamptz,
+            Date32 | Date64 => DataType::Date,
+            Time32(Second) | Time32(Millisecond) | Time64(Microsecond) | Time64(Nanosecond) => {
+                DataType::Time
+            }
+            Timestamp(_, None) => DataType::Timestamp,
+            Timestamp(_, Some(_)) => DataType::Timestamptz,
             Interval(MonthDayNano) => DataType::Interval,
             Utf8 => DataType::Varchar,
             Binary => DataType::Bytea,
             LargeUtf8 => self.from_large_utf8()?,
             LargeBinary => self.from_large_binary()?,
+            Utf8View => DataType::Varchar,
+            BinaryView => DataType::Bytea,
             List(field) => DataType::List(Box::new(self.from_field(field)?)),
             Struct(fields) => DataType::Struct(self.from_fields(fields)?),
+            Map(entries_field, _sorted) => DataType::List(Box::new(DataType::Struct(
+                self.map_entry_struct_type(entries_field)?,
+            ))),
+            FixedSizeList(field, _) => DataType::List(Box::new(self.from_field(field)?)),
+            LargeList(field) => DataType::List(Box::new(self.from_field(field)?)),
+            Dictionary(_key_type, value_type) => {
+                self.from_field(&arrow_schema::Field::new("", (**value_type).clone(), true))?
+            }
             t => {
                 return Err(ArrayError::from_arrow(format!(
                     "unsupported arrow data type: {t:?}"
@@ -482,6 +715,31 @@ pub trait FromArrow {
         Ok(DataType::Bytea)
     }
 
+    /// Builds the two-field `StructType` (key, value) for an Arrow map's `entries` field,
+    /// matching the key/value children positionally rather than by name since producers name
+    /// them differently (`key`/`value`, `keys`/`values`, `key_value`, ...).
+    fn map_entry_struct_type(
+        &self,
+        entries_field: &arrow_schema::Field,
+    ) -> Result<StructType, ArrayError> {
+        let arrow_schema::DataType::Struct(entry_fields) = entries_field.data_type() else {
+            return Err(ArrayError::from_arrow(
+                "map entries field must be a struct".to_string(),
+            ));
+        };
+        if entry_fields.len() != 2 {
+            return Err(ArrayError::from_arrow(
+                "map entries struct must have exactly two fields".to_string(),
+            ));
+        }
+        let key_field = &entry_fields[0];
+        let value_field = &entry_fields[1];
+        Ok(StructType::new(vec![
+            (key_field.name().clone(), self.from_field(key_field)?),
+            (value_field.name().clone(), self.from_field(value_field)?),
+        ]))
+    }
+
     /// Converts Arrow extension type to RisingWave `DataType`.
     fn from_extension_type(
         &self,
@@ -517,14 +775,28 @@ pub trait FromArrow {
             Int16 => self.from_int16_array(array.as_any().downcast_ref().unwrap()),
             Int32 => self.from_int32_array(array.as_any().downcast_ref().unwrap()),
             Int64 => self.from_int64_array(array.as_any().downcast_ref().unwrap()),
+            Decimal128(_, _) => self.from_decimal128_array(array.as_any().downcast_ref().unwrap()),
             Decimal256(_, _) => self.from_int256_array(array.as_any().downcast_ref().unwrap()),
             Float32 => self.from_float32_array(array.as_any().downcast_ref().unwrap()),
             Float64 => self.from_float64_array(array.as_any().downcast_ref().unwrap()),
             Date32 => self.from_date32_array(array.as_any().downcast_ref().unwrap()),
+            Date64 => self.from_date64_array(array.as_any().downcast_ref().unwrap()),
+            Time32(Second) => self.from_time32s_array(array.as_any().downcast_ref().unwrap()),
+            Time32(Millisecond) => {
+                self.from_time32ms_array(array.as_any().downcast_ref().unwrap())
+            }
             Time64(Microsecond) => self.from_time64us_array(array.as_any().downcast_ref().unwrap()),
+            Time64(Nanosecond) => self.from_time64ns_array(array.as_any().downcast_ref().unwrap()),
+            Timestamp(Second, _) => self.from_timestamps_array(array.as_any().downcast_ref().unwrap()),
+            Timestamp(Millisecond, _) => {
+                self.from_timestampms_array(array.as_any().downcast_ref().unwrap())
+            }
             Timestamp(Microsecond, _) => {
                 self.from_timestampus_array(array.as_any().downcast_ref().unwrap())
             }
+            Timestamp(Nanosecond, _) => {
+                self.from_timestampns_array(array.as_any().downcast_ref().unwrap())
+            }
             Interval(MonthDayNano) => {
                 self.from_interval_array(array.as_any().downcast_ref().unwrap())
             }
@@ -532,14 +804,58 @@ pub trait FromArrow {
             Binary => self.from_binary_array(array.as_any().downcast_ref().unwrap()),
             LargeUtf8 => self.from_large_utf8_array(array.as_any().downcast_ref().unwrap()),
             LargeBinary => self.from_large_binary_array(array.as_any().downcast_ref().unwrap()),
+            Utf8View => self.from_utf8_view_array(array.as_any().downcast_ref().unwrap()),
+            BinaryView => self.from_binary_view_array(array.as_any().downcast_ref().unwrap()),
             List(_) => self.from_list_array(array.as_any().downcast_ref().unwrap()),
             Struct(_) => self.from_struct_array(array.as_any().downcast_ref().unwrap()),
+            Map(_, _) => self.from_map_array(array.as_any().downcast_ref().unwrap()),
+            FixedSizeList(_, _) => {
+                self.from_fixed_size_list_array(array.as_any().downcast_ref().unwrap())
+            }
+            LargeList(_) => self.from_large_list_array(array.as_any().downcast_ref().unwrap()),
+            Dictionary(key_type, _) => self.from_dictionary_array(key_type, array),
             t => Err(ArrayError::from_arrow(format!(
                 "unsupported arrow data type: {t:?}",
             ))),
         }
     }
 
+    /// Converts Arrow dictionary-encoded `Array` to RisingWave `ArrayImpl` by materializing it
+    /// into a flat array of the dictionary's value type.
+    ///
+    /// The default implementation casts the dictionary to its plain value type and routes
+    /// through [`FromArrow::from_array`]; override this to decode without the intermediate cast.
+    fn from_dictionary_array(
+        &self,
+        key_type: &arrow_schema::DataType,
+        array: &arrow_array::ArrayRef,
+    ) -> Result<ArrayImpl, ArrayError> {
+        use arrow_schema::DataType::*;
+
+        let value_type = match array.data_type() {
+            Dictionary(_, value_type) => value_type.as_ref().clone(),
+            _ => unreachable!("from_dictionary_array called on non-dictionary array"),
+        };
+        if !matches!(
+            key_type,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+        ) {
+            return Err(ArrayError::from_arrow(format!(
+                "unsupported dictionary key type: {key_type:?}"
+            )));
+        }
+        // A dictionary whose values are themselves dictionary-encoded (some columnar readers
+        // nest encodings, e.g. after a union of batches with different dictionaries) needs to
+        // be unwrapped one level at a time, since a single `cast` call only flattens the
+        // outermost layer.
+        let mut flat = arrow_cast::cast(array, &value_type).map_err(ArrayError::from_arrow)?;
+        while let Dictionary(_, inner_value_type) = flat.data_type().clone() {
+            flat = arrow_cast::cast(&flat, &inner_value_type).map_err(ArrayError::from_arrow)?;
+        }
+        let field = arrow_schema::Field::new("", flat.data_type().clone(), true);
+        self.from_array(&field, &flat)
+    }
+
     /// Converts Arrow extension array to RisingWave `ArrayImpl`.
     fn from_extension_array(
         &self,
@@ -594,6 +910,15 @@ pub trait FromArrow {
         Ok(ArrayImpl::Int256(array.into()))
     }
 
+    /// Converts a native Arrow `Decimal128` array (as opposed to the `arrowudf.decimal` text
+    /// extension) to RisingWave `Decimal`, dividing the integer mantissa by `10^scale`.
+    fn from_decimal128_array(
+        &self,
+        array: &arrow_array::Decimal128Array,
+    ) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Decimal(array.try_into()?))
+    }
+
     fn from_float32_array(
         &self,
         array: &arrow_array::Float32Array,
@@ -612,6 +937,19 @@ pub trait FromArrow {
         Ok(ArrayImpl::Date(array.into()))
     }
 
+    /// Converts Arrow `Date64` (milliseconds-since-epoch) to RisingWave `Date`.
+    fn from_date64_array(&self, array: &arrow_array::Date64Array) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Date(
+            array
+                .iter()
+                // `div_euclid`, not `/`, so a negative, non-midnight timestamp floors to the
+                // preceding day instead of truncating toward zero onto the following one
+                // (e.g. -1ms, the last millisecond of 1969-12-31, must land on day -1, not 0).
+                .map(|o| o.map(|millis| Date::from_arrow(millis.div_euclid(86_400_000) as i32)))
+                .collect(),
+        ))
+    }
+
     fn from_time64us_array(
         &self,
         array: &arrow_array::Time64MicrosecondArray,
@@ -619,6 +957,43 @@ pub trait FromArrow {
         Ok(ArrayImpl::Time(array.into()))
     }
 
+    /// Converts Arrow `Time32(Second)` to RisingWave `Time`.
+    fn from_time32s_array(
+        &self,
+        array: &arrow_array::Time32SecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        let micros = collect_scaled(array.iter(), |v| scale_to_micros(v, 1_000_000, "second"))?;
+        Ok(ArrayImpl::Time(
+            micros.into_iter().map(|o| o.map(Time::from_arrow)).collect(),
+        ))
+    }
+
+    /// Converts Arrow `Time32(Millisecond)` to RisingWave `Time`.
+    fn from_time32ms_array(
+        &self,
+        array: &arrow_array::Time32MillisecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        let micros = collect_scaled(array.iter(), |v| scale_to_micros(v, 1_000, "millisecond"))?;
+        Ok(ArrayImpl::Time(
+            micros.into_iter().map(|o| o.map(Time::from_arrow)).collect(),
+        ))
+    }
+
+    /// Converts Arrow `Time64(Nanosecond)` to RisingWave `Time`, truncating to microseconds.
+    fn from_time64ns_array(
+        &self,
+        array: &arrow_array::Time64NanosecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Time(
+            array
+                .iter()
+                // `div_euclid` so a pre-epoch (negative) nanosecond value floors to the correct
+                // microsecond instead of truncating toward zero.
+                .map(|o| o.map(|ns| Time::from_arrow(ns.div_euclid(1_000))))
+                .collect(),
+        ))
+    }
+
     fn from_timestampus_array(
         &self,
         array: &arrow_array::TimestampMicrosecondArray,
@@ -626,6 +1001,54 @@ pub trait FromArrow {
         Ok(ArrayImpl::Timestamp(array.into()))
     }
 
+    /// Converts Arrow `Timestamp(Second, _)` to RisingWave `Timestamp`.
+    fn from_timestamps_array(
+        &self,
+        array: &arrow_array::TimestampSecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        let micros = collect_scaled(array.iter(), |v| scale_to_micros(v, 1_000_000, "second"))?;
+        Ok(ArrayImpl::Timestamp(
+            micros.into_iter().map(|o| o.map(Timestamp::from_arrow)).collect(),
+        ))
+    }
+
+    /// Converts Arrow `Timestamp(Millisecond, _)` to RisingWave `Timestamp`.
+    fn from_timestampms_array(
+        &self,
+        array: &arrow_array::TimestampMillisecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        let micros = collect_scaled(array.iter(), |v| scale_to_micros(v, 1_000, "millisecond"))?;
+        // `Timestamp::with_micros` splits with `div_euclid`/`rem_euclid`, so a negative,
+        // non-whole-second value (e.g. -1ms) stays valid instead of yielding a negative
+        // nanosecond part.
+        Ok(ArrayImpl::Timestamp(
+            micros
+                .into_iter()
+                .map(|o| o.map(Timestamp::with_micros).transpose())
+                .collect::<Result<_, _>>()
+                .map_err(|e| ArrayError::from_arrow(e.to_string()))?,
+        ))
+    }
+
+    /// Converts Arrow `Timestamp(Nanosecond, _)` to RisingWave `Timestamp`, truncating to
+    /// microseconds.
+    fn from_timestampns_array(
+        &self,
+        array: &arrow_array::TimestampNanosecondArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Timestamp(
+            array
+                .iter()
+                // `div_euclid` so a pre-epoch (negative) nanosecond value floors to the correct
+                // microsecond instead of truncating toward zero (e.g. -1ns must floor to -1us,
+                // not round up to 0us); `Timestamp::with_micros` then keeps that negative,
+                // non-whole-second value valid.
+                .map(|o| o.map(|ns| Timestamp::with_micros(ns.div_euclid(1_000))).transpose())
+                .collect::<Result<_, _>>()
+                .map_err(|e| ArrayError::from_arrow(e.to_string()))?,
+        ))
+    }
+
     fn from_interval_array(
         &self,
         array: &arrow_array::IntervalMonthDayNanoArray,
@@ -655,10 +1078,31 @@ pub trait FromArrow {
         Ok(ArrayImpl::Bytea(array.into()))
     }
 
+    /// Converts Arrow's newer `Utf8View` layout to RisingWave `Utf8Array`. This is the read
+    /// (`FromArrow`) direction; the opposite, `ToArrow` direction -- emitting `Varchar`/`Bytea`
+    /// as the view layout -- is `prefer_string_view`/`utf8_to_arrow` above.
+    fn from_utf8_view_array(
+        &self,
+        array: &arrow_array::StringViewArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Utf8(array.iter().collect()))
+    }
+
+    /// Converts Arrow's newer `BinaryView` layout to RisingWave `BytesArray`.
+    fn from_binary_view_array(
+        &self,
+        array: &arrow_array::BinaryViewArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        Ok(ArrayImpl::Bytea(array.iter().collect()))
+    }
+
     fn from_list_array(&self, array: &arrow_array::ListArray) -> Result<ArrayImpl, ArrayError> {
         use arrow_array::Array;
         let arrow_schema::DataType::List(field) = array.data_type() else {
-            panic!("nested field types cannot be determined.");
+            return Err(ArrayError::from_arrow(format!(
+                "expected a list array, got {:?}",
+                array.data_type()
+            )));
         };
         Ok(ArrayImpl::List(ListArray {
             value: Box::new(self.from_array(field, array.values())?),
@@ -670,11 +1114,107 @@ pub trait FromArrow {
         }))
     }
 
+    /// Converts Arrow `MapArray` to RisingWave `ArrayImpl`, treating the map as a
+    /// `List<Struct<key, value>>`: the entry struct's two child fields are matched
+    /// positionally (first = key, second = value) rather than by name.
+    fn from_map_array(&self, array: &arrow_array::MapArray) -> Result<ArrayImpl, ArrayError> {
+        use arrow_array::Array;
+
+        let arrow_schema::DataType::Map(entries_field, _) = array.data_type() else {
+            return Err(ArrayError::from_arrow(
+                "expected arrow map array".to_string(),
+            ));
+        };
+        let entries = array.entries();
+        let arrow_schema::DataType::Struct(entry_fields) = entries.data_type() else {
+            return Err(ArrayError::from_arrow(
+                "map entries array must be a struct array".to_string(),
+            ));
+        };
+        if entry_fields.len() != 2 {
+            return Err(ArrayError::from_arrow(
+                "map entries struct must have exactly two fields".to_string(),
+            ));
+        }
+        let element_type = self.map_entry_struct_type(entries_field)?;
+        let key = self.from_array(&entry_fields[0], entries.column(0))?;
+        let value = self.from_array(&entry_fields[1], entries.column(1))?;
+        let struct_array = StructArray::new(
+            element_type,
+            vec![Arc::new(key), Arc::new(value)],
+            (0..entries.len()).map(|i| entries.is_valid(i)).collect(),
+        );
+        Ok(ArrayImpl::List(ListArray {
+            value: Box::new(ArrayImpl::Struct(struct_array)),
+            bitmap: match array.nulls() {
+                Some(nulls) => nulls.iter().collect(),
+                None => Bitmap::ones(array.len()),
+            },
+            offsets: array.offsets().iter().map(|o| *o as u32).collect(),
+        }))
+    }
+
+    /// Converts Arrow `FixedSizeListArray` to RisingWave `ArrayImpl` by synthesizing offsets at
+    /// the fixed stride `n` and applying the outer validity bitmap.
+    fn from_fixed_size_list_array(
+        &self,
+        array: &arrow_array::FixedSizeListArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        use arrow_array::Array;
+        let arrow_schema::DataType::FixedSizeList(field, n) = array.data_type() else {
+            return Err(ArrayError::from_arrow(
+                "expected fixed size list array".to_string(),
+            ));
+        };
+        let n = *n as u32;
+        let values = self.from_array(field, array.values())?;
+        Ok(ArrayImpl::List(ListArray {
+            value: Box::new(values),
+            bitmap: match array.nulls() {
+                Some(nulls) => nulls.iter().collect(),
+                None => Bitmap::ones(array.len()),
+            },
+            offsets: (0..=array.len() as u32).map(|i| i * n).collect(),
+        }))
+    }
+
+    /// Converts Arrow `LargeListArray` (i64 offsets) to RisingWave `ArrayImpl`, identically to
+    /// [`FromArrow::from_list_array`] aside from the offset width.
+    fn from_large_list_array(
+        &self,
+        array: &arrow_array::LargeListArray,
+    ) -> Result<ArrayImpl, ArrayError> {
+        use arrow_array::Array;
+        let arrow_schema::DataType::LargeList(field) = array.data_type() else {
+            return Err(ArrayError::from_arrow(
+                "expected large list array".to_string(),
+            ));
+        };
+        Ok(ArrayImpl::List(ListArray {
+            value: Box::new(self.from_array(field, array.values())?),
+            bitmap: match array.nulls() {
+                Some(nulls) => nulls.iter().collect(),
+                None => Bitmap::ones(array.len()),
+            },
+            offsets: array.offsets().iter().map(|&o| o as u32).collect(),
+        }))
+    }
+
     fn from_struct_array(&self, array: &arrow_array::StructArray) -> Result<ArrayImpl, ArrayError> {
         use arrow_array::Array;
         let arrow_schema::DataType::Struct(fields) = array.data_type() else {
-            panic!("nested field types cannot be determined.");
+            return Err(ArrayError::from_arrow(format!(
+                "expected a struct array, got {:?}",
+                array.data_type()
+            )));
         };
+        if array.columns().len() != fields.len() {
+            return Err(ArrayError::from_arrow(format!(
+                "struct array has {} columns but its declared type has {} fields",
+                array.columns().len(),
+                fields.len()
+            )));
+        }
         Ok(ArrayImpl::Struct(StructArray::new(
             self.from_fields(fields)?,
             array
@@ -901,6 +1441,73 @@ impl FromIntoArrow for Interval {
     }
 }
 
+/// Scales a temporal value measured in whole `unit`s into microseconds, erroring on overflow
+/// instead of silently wrapping.
+fn scale_to_micros(value: i64, factor: i64, unit: &str) -> Result<i64, ArrayError> {
+    value
+        .checked_mul(factor)
+        .ok_or_else(|| ArrayError::from_arrow(format!("{unit} timestamp overflows i64 microseconds")))
+}
+
+/// Applies a fallible microsecond-scaling function over an Arrow primitive array's values,
+/// threading the `Result` out of the `Option`/iterator so callers can `?` once.
+fn collect_scaled<T, F>(
+    iter: impl Iterator<Item = Option<T>>,
+    mut f: F,
+) -> Result<Vec<Option<i64>>, ArrayError>
+where
+    T: Into<i64>,
+    F: FnMut(i64) -> Result<i64, ArrayError>,
+{
+    iter.map(|o| o.map(|v| f(v.into())).transpose()).collect()
+}
+
+/// `rust_decimal::Decimal` stores its mantissa in 96 bits and cannot represent a scale beyond
+/// this many digits after the point; `rescale` to a scale outside `0..=MAX_DECIMAL_SCALE` either
+/// panics (negative, via the `as u32` cast wrapping to a huge value) or silently rounds instead
+/// of erroring (out-of-range positive scale), so both must be rejected up front.
+const MAX_DECIMAL_SCALE: i8 = 28;
+
+/// Rescales a RisingWave `Decimal` to the fixed `i128` mantissa that Arrow's `Decimal128`/
+/// `Decimal256` expect at the given scale, mapping `NaN`/`+Inf`/`-Inf` to the same sentinel
+/// values the `Decimal128Array` reader already recognizes.
+fn decimal_to_fixed_i128(value: Decimal, precision: u8, scale: i8) -> Result<i128, ArrayError> {
+    const NAN: i128 = i128::MIN + 1;
+    match value {
+        Decimal::NaN => Ok(NAN),
+        Decimal::PositiveInf => Ok(i128::MAX),
+        Decimal::NegativeInf => Ok(i128::MIN),
+        Decimal::Normalized(mut d) => {
+            if !(0..=MAX_DECIMAL_SCALE).contains(&scale) {
+                return Err(ArrayError::to_arrow(format!(
+                    "arrow decimal scale {scale} is out of range 0..={MAX_DECIMAL_SCALE}"
+                )));
+            }
+            let scale = scale as u32;
+            d.rescale(scale);
+            if d.scale() != scale {
+                // `rescale` couldn't hit the requested scale without overflowing the 96-bit
+                // coefficient, so it rounded to a coarser one instead of erroring -- surface
+                // that as a conversion failure rather than emitting a silently wrong value.
+                return Err(ArrayError::to_arrow(format!(
+                    "decimal {d} cannot be rescaled to {scale} without losing precision"
+                )));
+            }
+            let mantissa: i128 = d.mantissa();
+            // `i128` can hold at most 38-39 decimal digits, so a precision beyond that never
+            // rejects anything the mantissa type could represent in the first place.
+            if let Some(limit) = 10u128.checked_pow(precision as u32) {
+                if mantissa.unsigned_abs() >= limit {
+                    return Err(ArrayError::to_arrow(format!(
+                        "decimal {d} has more digits than precision {precision} allows"
+                    )));
+                }
+            }
+            Ok(mantissa)
+        }
+    }
+}
+
 impl From<&DecimalArray> for arrow_array::LargeBinaryArray {
     fn from(array: &DecimalArray) -> Self {
         let mut builder =
@@ -1091,8 +1698,25 @@ impl From<&arrow_array::Decimal256Array> for Int256Array {
 
 #[cfg(test)]
 mod tests {
+    use arrow_array::Array as _;
+
     use super::*;
 
+    /// A no-override [`FromArrow`] implementor, for exercising units/types that only go through
+    /// [`FromArrow::from_array`] (e.g. `Date64`, `Time32`) and so have no `converts!`-generated
+    /// `From` impl to round-trip through directly.
+    struct Convert;
+    impl FromArrow for Convert {}
+
+    fn from_array(
+        data_type: arrow_schema::DataType,
+        array: impl arrow_array::Array + 'static,
+    ) -> ArrayImpl {
+        let field = arrow_schema::Field::new("x", data_type, true);
+        let array: arrow_array::ArrayRef = Arc::new(array);
+        Convert.from_array(&field, &array).unwrap()
+    }
+
     #[test]
     fn bool() {
         let array = BoolArray::from_iter([None, Some(false), Some(true)]);
@@ -1251,4 +1875,284 @@ mod tests {
         let arrow = arrow_array::Decimal256Array::from(&array);
         assert_eq!(Int256Array::from(&arrow), array);
     }
+
+    #[test]
+    fn date64() {
+        // -1ms is the last millisecond of 1969-12-31, one day before the epoch: it must floor
+        // to day -1, not truncate toward zero onto day 0.
+        let arrow = arrow_array::Date64Array::from(vec![None, Some(-1), Some(0), Some(86_400_000)]);
+        let result = from_array(arrow_schema::DataType::Date64, arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::Date(DateArray::from_iter([
+                None,
+                Date::with_days(-1).ok(),
+                Date::with_days(0).ok(),
+                Date::with_days(1).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn time32_second() {
+        let arrow = arrow_array::Time32SecondArray::from(vec![None, Some(0), Some(3600)]);
+        let result = from_array(arrow_schema::DataType::Time32(arrow_schema::TimeUnit::Second), arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::Time(TimeArray::from_iter([
+                None,
+                Time::with_micro(0).ok(),
+                Time::with_micro(3_600 * 1_000_000).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn time32_millisecond() {
+        let arrow = arrow_array::Time32MillisecondArray::from(vec![None, Some(0), Some(3_600_123)]);
+        let result = from_array(
+            arrow_schema::DataType::Time32(arrow_schema::TimeUnit::Millisecond),
+            arrow,
+        );
+        assert_eq!(
+            result,
+            ArrayImpl::Time(TimeArray::from_iter([
+                None,
+                Time::with_micro(0).ok(),
+                Time::with_micro(3_600_123 * 1_000).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn time64_nanosecond() {
+        let arrow = arrow_array::Time64NanosecondArray::from(vec![None, Some(0), Some(86_399_999_999_999)]);
+        let result = from_array(
+            arrow_schema::DataType::Time64(arrow_schema::TimeUnit::Nanosecond),
+            arrow,
+        );
+        assert_eq!(
+            result,
+            ArrayImpl::Time(TimeArray::from_iter([
+                None,
+                Time::with_micro(0).ok(),
+                Time::with_micro(86_399_999_999).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn timestamp_second() {
+        let arrow = arrow_array::TimestampSecondArray::from(vec![None, Some(-1), Some(1_000_000_000)]);
+        let result = from_array(
+            arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Second, None),
+            arrow,
+        );
+        assert_eq!(
+            result,
+            ArrayImpl::Timestamp(TimestampArray::from_iter([
+                None,
+                Timestamp::with_micros(-1_000_000).ok(),
+                Timestamp::with_micros(1_000_000_000 * 1_000_000).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn timestamp_millisecond() {
+        let arrow =
+            arrow_array::TimestampMillisecondArray::from(vec![None, Some(-1), Some(1_000_000_000_000)]);
+        let result = from_array(
+            arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Millisecond, None),
+            arrow,
+        );
+        assert_eq!(
+            result,
+            ArrayImpl::Timestamp(TimestampArray::from_iter([
+                None,
+                Timestamp::with_micros(-1_000).ok(),
+                Timestamp::with_micros(1_000_000_000_000 * 1_000).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn timestamp_nanosecond() {
+        // -1ns is the negative-timestamp edge case: it must floor to -1us, not truncate toward
+        // zero onto 0us.
+        let arrow = arrow_array::TimestampNanosecondArray::from(vec![None, Some(-1), Some(1_500)]);
+        let result = from_array(
+            arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, None),
+            arrow,
+        );
+        assert_eq!(
+            result,
+            ArrayImpl::Timestamp(TimestampArray::from_iter([
+                None,
+                Timestamp::with_micros(-1).ok(),
+                Timestamp::with_micros(1).ok(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn dictionary() {
+        let arrow = arrow_array::DictionaryArray::<arrow_array::types::Int32Type>::from_iter([
+            Some("a"),
+            None,
+            Some("b"),
+            Some("a"),
+        ]);
+        let data_type = arrow_schema::DataType::Dictionary(
+            Box::new(arrow_schema::DataType::Int32),
+            Box::new(arrow_schema::DataType::Utf8),
+        );
+        let result = from_array(data_type, arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::Utf8(Utf8Array::from_iter([
+                Some("a"),
+                None,
+                Some("b"),
+                Some("a"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn map() {
+        let mut builder = arrow_array::builder::MapBuilder::new(
+            None,
+            arrow_array::builder::StringBuilder::new(),
+            arrow_array::builder::Int32Builder::new(),
+        );
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let arrow = builder.finish();
+
+        let arrow_schema::DataType::Map(entries_field, _) = arrow.data_type().clone() else {
+            unreachable!()
+        };
+        let arrow_schema::DataType::Struct(entry_fields) = entries_field.data_type() else {
+            unreachable!()
+        };
+        let element_type = StructType::new(vec![
+            (entry_fields[0].name().clone(), DataType::Varchar),
+            (entry_fields[1].name().clone(), DataType::Int32),
+        ]);
+
+        let data_type = arrow.data_type().clone();
+        let result = from_array(data_type, arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::List(ListArray {
+                value: Box::new(ArrayImpl::Struct(StructArray::new(
+                    element_type,
+                    vec![
+                        Arc::new(ArrayImpl::Utf8(Utf8Array::from_iter([
+                            Some("a"),
+                            Some("b"),
+                            Some("c")
+                        ]))),
+                        Arc::new(ArrayImpl::Int32(I32Array::from_iter([
+                            Some(1),
+                            Some(2),
+                            Some(3)
+                        ]))),
+                    ],
+                    [true, true, true].into_iter().collect(),
+                ))),
+                bitmap: [true, false, true].into_iter().collect(),
+                offsets: vec![0, 1, 1, 3],
+            })
+        );
+    }
+
+    #[test]
+    fn fixed_size_list() {
+        let arrow = arrow_array::FixedSizeListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>(
+            [
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(3), Some(4)]),
+            ],
+            2,
+        );
+        let item_field = Box::new(arrow_schema::Field::new("item", arrow_schema::DataType::Int32, true));
+        let result = from_array(
+            arrow_schema::DataType::FixedSizeList(item_field, 2),
+            arrow,
+        );
+        let ArrayImpl::List(result) = result else {
+            panic!("expected a list array, got {result:?}");
+        };
+        assert_eq!(
+            result,
+            ListArray {
+                value: Box::new(ArrayImpl::Int32(I32Array::from_iter([
+                    Some(1),
+                    Some(2),
+                    None,
+                    None,
+                    Some(3),
+                    Some(4),
+                ]))),
+                bitmap: [true, false, true].into_iter().collect(),
+                offsets: vec![0, 2, 4, 6],
+            }
+        );
+    }
+
+    #[test]
+    fn large_list() {
+        let arrow = arrow_array::LargeListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+        ]);
+        let item_field = Box::new(arrow_schema::Field::new("item", arrow_schema::DataType::Int32, true));
+        let result = from_array(arrow_schema::DataType::LargeList(item_field), arrow);
+        let ArrayImpl::List(result) = result else {
+            panic!("expected a list array, got {result:?}");
+        };
+        assert_eq!(
+            result,
+            ListArray {
+                value: Box::new(ArrayImpl::Int32(I32Array::from_iter([
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                ]))),
+                bitmap: [true, false, true].into_iter().collect(),
+                offsets: vec![0, 3, 3, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn string_view() {
+        let arrow = arrow_array::StringViewArray::from(vec![None, Some("array"), Some("arrow")]);
+        let result = from_array(arrow_schema::DataType::Utf8View, arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::Utf8(Utf8Array::from_iter([None, Some("array"), Some("arrow")]))
+        );
+    }
+
+    #[test]
+    fn binary_view() {
+        let arrow = arrow_array::BinaryViewArray::from(vec![None, Some("array".as_bytes())]);
+        let result = from_array(arrow_schema::DataType::BinaryView, arrow);
+        assert_eq!(
+            result,
+            ArrayImpl::Bytea(BytesArray::from_iter([None, Some("array".as_bytes())]))
+        );
+    }
 }
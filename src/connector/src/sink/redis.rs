@@ -16,14 +16,16 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::anyhow;
 use async_trait::async_trait;
-use redis::aio::MultiplexedConnection;
-use redis::cluster::{ClusterClient, ClusterConnection, ClusterPipeline};
+use redis::aio::ConnectionManager;
+use redis::cluster::{ClusterClient, ClusterPipeline};
+use redis::cluster_async::ClusterConnection;
 use redis::{Client as RedisClient, Pipeline};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
 use serde_derive::Deserialize;
 use serde_json::Value;
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
 use with_options::WithOptions;
 
 use super::catalog::SinkFormatDesc;
@@ -43,10 +45,101 @@ pub const REDIS_SINK: &str = "redis";
 pub const KEY_FORMAT: &str = "key_format";
 pub const VALUE_FORMAT: &str = "value_format";
 
+#[serde_as]
 #[derive(Deserialize, Debug, Clone, WithOptions)]
 pub struct RedisCommon {
     #[serde(rename = "redis.url")]
     pub url: String,
+
+    /// TTL (in seconds) applied to every key written by this sink via `SET key value EX n`.
+    /// Mutually exclusive with `redis.expire_ms`; `del` is unaffected.
+    #[serde(rename = "redis.expire_seconds", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub expire_seconds: Option<u64>,
+
+    /// TTL (in milliseconds) applied to every key written by this sink via `SET key value PX
+    /// n`. Mutually exclusive with `redis.expire_seconds`; `del` is unaffected.
+    #[serde(rename = "redis.expire_ms", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub expire_ms: Option<u64>,
+
+    /// Name of the Sentinel-monitored master group to connect to. Setting this switches
+    /// `build_conn_and_pipe` to resolve the current master through Sentinel instead of treating
+    /// `redis.url` as a direct server/cluster address.
+    #[serde(rename = "redis.sentinel.master", default)]
+    pub sentinel_master: Option<String>,
+
+    /// JSON array of Sentinel addresses as `redis://host:port` URLs, e.g.
+    /// `["redis://10.0.0.1:26379","redis://10.0.0.2:26379"]`.
+    /// Required when `redis.sentinel.master` is set.
+    #[serde(rename = "redis.sentinel.nodes", default)]
+    pub sentinel_nodes: Option<String>,
+
+    #[serde(rename = "redis.sentinel.username", default)]
+    pub sentinel_username: Option<String>,
+
+    #[serde(rename = "redis.sentinel.password", default)]
+    pub sentinel_password: Option<String>,
+
+    /// ACL username, applied via `RedisConnectionInfo` rather than being embedded in
+    /// `redis.url`. Supported on Redis 6+.
+    #[serde(rename = "redis.username", default)]
+    pub username: Option<String>,
+
+    #[serde(rename = "redis.password", default)]
+    pub password: Option<String>,
+
+    /// Connect over TLS (`rediss://`-equivalent) instead of plain TCP.
+    #[serde(rename = "redis.tls.enabled", default)]
+    pub tls_enabled: bool,
+
+    /// Skip server certificate verification. Only takes effect when `redis.tls.enabled` is
+    /// `true`; intended for self-signed certs in test/staging environments, not production.
+    #[serde(rename = "redis.tls.insecure", default)]
+    pub tls_insecure: bool,
+
+    /// Path to a PEM-encoded CA certificate to trust in addition to the system roots, for
+    /// managed Redis/Valkey deployments fronted by a private CA. Only takes effect when
+    /// `redis.tls.enabled` is `true`; mutually exclusive in practice with `redis.tls.insecure`,
+    /// since the latter skips verification entirely. Applied to the single-node connection
+    /// only: the cluster connection (a JSON array `redis.url`) does not read this option.
+    #[serde(rename = "redis.tls.ca_cert_path", default)]
+    pub tls_ca_cert_path: Option<String>,
+}
+
+/// The TTL to attach to a `SET` issued by [`RedisPipe::set`], derived from `redis.expire_seconds`
+/// / `redis.expire_ms`.
+#[derive(Clone, Copy, Debug)]
+pub enum RedisExpire {
+    Seconds(u64),
+    Milliseconds(u64),
+}
+
+impl RedisCommon {
+    fn expire(&self) -> Result<Option<RedisExpire>> {
+        match (self.expire_seconds, self.expire_ms) {
+            (Some(_), Some(_)) => Err(SinkError::Config(anyhow!(
+                "redis.expire_seconds and redis.expire_ms are mutually exclusive"
+            ))),
+            (Some(secs), None) => {
+                if secs == 0 {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.expire_seconds must be a positive integer"
+                    )));
+                }
+                Ok(Some(RedisExpire::Seconds(secs)))
+            }
+            (None, Some(ms)) => {
+                if ms == 0 {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.expire_ms must be a positive integer"
+                    )));
+                }
+                Ok(Some(RedisExpire::Milliseconds(ms)))
+            }
+            (None, None) => Ok(None),
+        }
+    }
 }
 
 pub enum RedisPipe {
@@ -59,10 +152,16 @@ impl RedisPipe {
         conn: &mut RedisConn,
     ) -> ConnectorResult<T> {
         match (self, conn) {
-            (RedisPipe::Cluster(pipe), RedisConn::Cluster(conn)) => Ok(pipe.query(conn)?),
+            (RedisPipe::Cluster(pipe), RedisConn::Cluster(conn)) => {
+                Ok(pipe.query_async(conn).await?)
+            }
             (RedisPipe::Single(pipe), RedisConn::Single(conn)) => {
                 Ok(pipe.query_async(conn).await?)
             }
+            (RedisPipe::Single(pipe), RedisConn::Sentinel(client)) => {
+                let mut conn = client.get_async_connection().await?;
+                Ok(pipe.query_async(&mut conn).await?)
+            }
             _ => Err(SinkError::Redis("RedisPipe and RedisConn not match".to_string()).into()),
         }
     }
@@ -74,14 +173,26 @@ impl RedisPipe {
         }
     }
 
-    pub fn set(&mut self, k: String, v: Vec<u8>) {
-        match self {
-            RedisPipe::Cluster(pipe) => {
+    pub fn set(&mut self, k: String, v: Vec<u8>, expire: Option<RedisExpire>) {
+        match (self, expire) {
+            (RedisPipe::Cluster(pipe), None) => {
                 pipe.set(k, v);
             }
-            RedisPipe::Single(pipe) => {
+            (RedisPipe::Cluster(pipe), Some(RedisExpire::Seconds(secs))) => {
+                pipe.set_ex(k, v, secs);
+            }
+            (RedisPipe::Cluster(pipe), Some(RedisExpire::Milliseconds(ms))) => {
+                pipe.pset_ex(k, v, ms);
+            }
+            (RedisPipe::Single(pipe), None) => {
                 pipe.set(k, v);
             }
+            (RedisPipe::Single(pipe), Some(RedisExpire::Seconds(secs))) => {
+                pipe.set_ex(k, v, secs);
+            }
+            (RedisPipe::Single(pipe), Some(RedisExpire::Milliseconds(ms))) => {
+                pipe.pset_ex(k, v, ms);
+            }
         };
     }
 
@@ -95,16 +206,115 @@ impl RedisPipe {
             }
         };
     }
+
+    pub fn hset(&mut self, k: String, field: String, v: Vec<u8>) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.hset(k, field, v);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.hset(k, field, v);
+            }
+        };
+    }
+
+    pub fn zadd(&mut self, k: String, member: String, score: f64) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.zadd(k, member, score);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.zadd(k, member, score);
+            }
+        };
+    }
+
+    pub fn hdel(&mut self, k: String, field: String) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.hdel(k, field);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.hdel(k, field);
+            }
+        };
+    }
+
+    pub fn zrem(&mut self, k: String, member: String) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.zrem(k, member);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.zrem(k, member);
+            }
+        };
+    }
+
+    pub fn xadd(&mut self, k: String, field: String, v: Vec<u8>) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.xadd(k, "*", &[(field, v)]);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.xadd(k, "*", &[(field, v)]);
+            }
+        };
+    }
+
+    pub fn publish(&mut self, channel: String, payload: Vec<u8>) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.publish(channel, payload);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.publish(channel, payload);
+            }
+        };
+    }
 }
 pub enum RedisConn {
-    // Redis deployed as a cluster, clusters with only one node should also use this conn
+    // Redis deployed as a cluster, clusters with only one node should also use this conn. The
+    // async cluster connection transparently re-routes/retries across a topology change or a
+    // dropped node socket.
     Cluster(ClusterConnection),
-    // Redis is not deployed as a cluster
-    Single(MultiplexedConnection),
+    // Redis is not deployed as a cluster. `ConnectionManager` re-establishes the socket on the
+    // next command (with backoff) if the server drops it, instead of failing outright.
+    Single(ConnectionManager),
+    // Fronted by Redis Sentinel: the master address is re-resolved through Sentinel on every
+    // commit, so a failover that elects a new master is picked up automatically.
+    Sentinel(redis::sentinel::SentinelClient),
 }
 
 impl RedisCommon {
     pub async fn build_conn_and_pipe(&self) -> ConnectorResult<(RedisConn, RedisPipe)> {
+        if let Some(master) = &self.sentinel_master {
+            let nodes_json = self.sentinel_nodes.as_ref().ok_or_else(|| {
+                SinkError::Redis(
+                    "redis.sentinel.nodes must be set when redis.sentinel.master is set"
+                        .to_string(),
+                )
+            })?;
+            let nodes: Vec<String> =
+                serde_json::from_str(nodes_json).map_err(|e| SinkError::Config(anyhow!(e)))?;
+            let node_conn_info = (self.sentinel_username.is_some()
+                || self.sentinel_password.is_some())
+            .then(|| redis::sentinel::SentinelNodeConnectionInfo {
+                tls_mode: None,
+                redis_connection_info: Some(redis::RedisConnectionInfo {
+                    db: 0,
+                    username: self.sentinel_username.clone(),
+                    password: self.sentinel_password.clone(),
+                }),
+            });
+            let client = redis::sentinel::SentinelClient::build(
+                nodes,
+                master.clone(),
+                node_conn_info,
+                redis::sentinel::SentinelServerType::Master,
+            )?;
+            return Ok((RedisConn::Sentinel(client), RedisPipe::Single(redis::pipe())));
+        }
         match serde_json::from_str(&self.url).map_err(|e| SinkError::Config(anyhow!(e))) {
             Ok(v) => {
                 if let Value::Array(list) = v {
@@ -122,9 +332,23 @@ impl RedisCommon {
                         })
                         .collect::<ConnectorResult<Vec<String>>>()?;
 
-                    let client = ClusterClient::new(list)?;
+                    let mut builder = redis::cluster::ClusterClientBuilder::new(list);
+                    if let Some(username) = &self.username {
+                        builder = builder.username(username.clone());
+                    }
+                    if let Some(password) = &self.password {
+                        builder = builder.password(password.clone());
+                    }
+                    if self.tls_enabled {
+                        builder = builder.tls(if self.tls_insecure {
+                            redis::cluster::TlsMode::Insecure
+                        } else {
+                            redis::cluster::TlsMode::Secure
+                        });
+                    }
+                    let conn = builder.build()?.get_async_connection().await?;
                     Ok((
-                        RedisConn::Cluster(client.get_connection()?),
+                        RedisConn::Cluster(conn),
                         RedisPipe::Cluster(redis::cluster::cluster_pipe()),
                     ))
                 } else {
@@ -132,14 +356,83 @@ impl RedisCommon {
                 }
             }
             Err(_) => {
-                let client = RedisClient::open(self.url.clone())?;
+                let client = RedisClient::open(self.connection_info()?)?;
                 Ok((
-                    RedisConn::Single(client.get_multiplexed_async_connection().await?),
+                    RedisConn::Single(ConnectionManager::new(client).await?),
                     RedisPipe::Single(redis::pipe()),
                 ))
             }
         }
     }
+
+    /// Builds a [`redis::ConnectionInfo`] from `redis.url`, with `redis.username`/`redis.password`
+    /// and `redis.tls.enabled` applied on top -- so secrets and TLS no longer have to be crammed
+    /// into the URL string itself.
+    fn connection_info(&self) -> ConnectorResult<redis::ConnectionInfo> {
+        use redis::IntoConnectionInfo;
+        let mut info = self.url.as_str().into_connection_info()?;
+        if let Some(username) = &self.username {
+            info.redis.username = Some(username.clone());
+        }
+        if let Some(password) = &self.password {
+            info.redis.password = Some(password.clone());
+        }
+        if self.tls_enabled {
+            if let redis::ConnectionAddr::Tcp(host, port) = info.addr {
+                let tls_params = self
+                    .tls_ca_cert_path
+                    .as_ref()
+                    .map(|path| -> ConnectorResult<_> {
+                        let root_cert = std::fs::read(path).map_err(|e| {
+                            SinkError::Config(anyhow!(
+                                "failed to read redis.tls.ca_cert_path `{path}`: {e}"
+                            ))
+                        })?;
+                        Ok(redis::TlsConnParams {
+                            client_tls: None,
+                            root_cert: Some(root_cert),
+                        })
+                    })
+                    .transpose()?;
+                info.addr = redis::ConnectionAddr::TcpTls {
+                    host,
+                    port,
+                    insecure: self.tls_insecure,
+                    tls_params,
+                };
+            }
+        }
+        Ok(info)
+    }
+}
+
+/// How this sink talks to Redis, set via `redis.mode`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, WithOptions)]
+#[serde(rename_all = "snake_case")]
+pub enum RedisMode {
+    /// Write rows as keys via `redis.data_type` (default).
+    #[default]
+    Sink,
+    /// `PUBLISH <channel> <payload>`, where `channel` is the row's formatted key. Turns the
+    /// sink into a fan-out producer for downstream pub-sub subscribers instead of a key-value
+    /// store; only append-only formats make sense here since there is nothing to delete.
+    Publish,
+}
+
+/// The Redis command family used to write each row, set via `redis.data_type`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, WithOptions)]
+#[serde(rename_all = "snake_case")]
+pub enum RedisDataType {
+    /// `SET key value` / `DEL key` (default).
+    #[default]
+    String,
+    /// `HSET key field value`, where `field` is the value of `redis.hash_field_column`.
+    Hash,
+    /// `ZADD key score member`, where `key` is `redis.sorted_set_key`, `score` is the value of
+    /// `redis.sorted_set_score_column`, and `member` is the row's formatted key.
+    SortedSet,
+    /// `XADD key * field value`, where `field` is the value of `redis.hash_field_column`.
+    Stream,
 }
 
 #[serde_as]
@@ -147,6 +440,29 @@ impl RedisCommon {
 pub struct RedisConfig {
     #[serde(flatten)]
     pub common: RedisCommon,
+
+    #[serde(rename = "redis.mode", default)]
+    pub mode: RedisMode,
+
+    #[serde(rename = "redis.data_type", default)]
+    pub data_type: RedisDataType,
+
+    /// Required when `redis.data_type` is `hash` or `stream`: the schema column whose value
+    /// becomes the hash/stream field name for each row.
+    #[serde(rename = "redis.hash_field_column", default)]
+    pub hash_field_column: Option<String>,
+
+    /// Required when `redis.data_type` is `sorted_set`: the schema column whose value is used
+    /// as the `ZADD` score.
+    #[serde(rename = "redis.sorted_set_score_column", default)]
+    pub sorted_set_score_column: Option<String>,
+
+    /// Required when `redis.data_type` is `sorted_set`: the fixed name of the `ZADD`/`ZREM`
+    /// target key, shared by every row. Must not be confused with the per-row member (the row's
+    /// formatted key) -- a sorted set has exactly one key and many members, so reusing the
+    /// row's key here would create one singleton sorted set per row instead of one shared one.
+    #[serde(rename = "redis.sorted_set_key", default)]
+    pub sorted_set_key: Option<String>,
 }
 
 impl RedisConfig {
@@ -213,6 +529,17 @@ impl Sink for RedisSink {
 
     async fn validate(&self) -> Result<()> {
         self.config.common.build_conn_and_pipe().await?;
+        self.config.common.expire()?;
+        if self.config.mode == RedisMode::Publish
+            && !matches!(
+                self.format_desc.format,
+                super::catalog::SinkFormat::AppendOnly
+            )
+        {
+            return Err(SinkError::Config(anyhow!(
+                "redis.mode = publish only supports FORMAT APPEND_ONLY: there is nothing to publish for a delete"
+            )));
+        }
         let all_set: HashSet<String> = self
             .schema
             .fields()
@@ -244,6 +571,53 @@ impl Sink for RedisSink {
             TemplateEncoder::check_string_format(key_format, &pk_set)?;
             TemplateEncoder::check_string_format(value_format, &all_set)?;
         }
+        match self.config.data_type {
+            RedisDataType::String => {}
+            RedisDataType::SortedSet => {
+                let score_column =
+                    self.config.sorted_set_score_column.as_ref().ok_or_else(|| {
+                        SinkError::Config(anyhow!(
+                            "redis.sorted_set_score_column must be set when redis.data_type = sorted_set"
+                        ))
+                    })?;
+                if !all_set.contains(score_column) {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.sorted_set_score_column `{score_column}` is not a column of the sink's schema"
+                    )));
+                }
+                if self.config.sorted_set_key.is_none() {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.sorted_set_key must be set when redis.data_type = sorted_set"
+                    )));
+                }
+            }
+            RedisDataType::Hash | RedisDataType::Stream => {
+                let field_column = self.config.hash_field_column.as_ref().ok_or_else(|| {
+                    SinkError::Config(anyhow!(
+                        "redis.hash_field_column must be set when redis.data_type = hash or stream"
+                    ))
+                })?;
+                if !all_set.contains(field_column) {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.hash_field_column `{field_column}` is not a column of the sink's schema"
+                    )));
+                }
+                // `XDEL` needs the server-generated entry id, which this sink never reads back
+                // off the pipelined `XADD` response, so a logical delete can't be translated
+                // into removing the right stream entry. Refuse the combination instead of
+                // silently dropping deletes or falling back to wiping the whole stream with `DEL`.
+                if self.config.data_type == RedisDataType::Stream
+                    && !matches!(
+                        self.format_desc.format,
+                        super::catalog::SinkFormat::AppendOnly
+                    )
+                {
+                    return Err(SinkError::Config(anyhow!(
+                        "redis.data_type = stream only supports FORMAT APPEND_ONLY: deletes cannot be translated into an XDEL without the entry id"
+                    )));
+                }
+            }
+        }
         Ok(())
     }
 }
@@ -261,21 +635,56 @@ struct RedisSinkPayloadWriter {
     conn: Option<RedisConn>,
     // the command pipeline for write-commit
     pipe: RedisPipe,
+    // TTL applied to every `SET`, from `redis.expire_seconds`/`redis.expire_ms`
+    expire: Option<RedisExpire>,
+    // how this sink talks to Redis, from `redis.mode`
+    mode: RedisMode,
+    // the command family used to write each row, from `redis.data_type`
+    data_type: RedisDataType,
+    hash_field_column: Option<String>,
+    sorted_set_score_column: Option<String>,
+    // the fixed `ZADD`/`ZREM` key shared by every row, from `redis.sorted_set_key`
+    sorted_set_key: Option<String>,
+    // the `hash_field_column` value last written for a given row key, so a later delete (which
+    // carries no row value to re-derive the field from) can issue a precise `HDEL key field`
+    // instead of wiping every sibling field on the key with `DEL`.
+    hash_fields: HashMap<String, String>,
 }
 
 impl RedisSinkPayloadWriter {
     pub async fn new(config: RedisConfig) -> Result<Self> {
         let (conn, pipe) = config.common.build_conn_and_pipe().await?;
         let conn = Some(conn);
+        let expire = config.common.expire()?;
 
-        Ok(Self { conn, pipe })
+        Ok(Self {
+            conn,
+            pipe,
+            expire,
+            mode: config.mode,
+            data_type: config.data_type,
+            hash_field_column: config.hash_field_column,
+            sorted_set_score_column: config.sorted_set_score_column,
+            sorted_set_key: config.sorted_set_key,
+            hash_fields: HashMap::new(),
+        })
     }
 
     #[cfg(test)]
     pub fn mock() -> Self {
         let conn = None;
         let pipe = RedisPipe::Single(redis::pipe());
-        Self { conn, pipe }
+        Self {
+            conn,
+            pipe,
+            expire: None,
+            mode: RedisMode::Sink,
+            data_type: RedisDataType::String,
+            hash_field_column: None,
+            sorted_set_score_column: None,
+            sorted_set_key: None,
+            hash_fields: HashMap::new(),
+        }
     }
 
     pub async fn commit(&mut self) -> Result<()> {
@@ -289,6 +698,39 @@ impl RedisSinkPayloadWriter {
         self.pipe.clear();
         Ok(())
     }
+
+    /// Parses an encoded row value as a JSON object and pulls out `column`'s value, for the
+    /// `hash`/`sorted_set`/`stream` data types where a field name or score is derived from one
+    /// of the row's own columns rather than being a fixed string.
+    fn json_column(v: &[u8], column: &str) -> Result<Value> {
+        let row: Value = serde_json::from_slice(v).map_err(|e| {
+            SinkError::Redis(format!(
+                "redis.data_type requires FORMAT ... ENCODE JSON so columns can be extracted: {e}"
+            ))
+        })?;
+        let Value::Object(row) = row else {
+            return Err(SinkError::Redis(
+                "expected the encoded row to be a JSON object".to_string(),
+            )
+            .into());
+        };
+        row.get(column)
+            .cloned()
+            .ok_or_else(|| SinkError::Redis(format!("column `{column}` not found in row")).into())
+    }
+
+    fn json_column_as_string(v: &[u8], column: &str) -> Result<String> {
+        Ok(match Self::json_column(v, column)? {
+            Value::String(s) => s,
+            other => other.to_string(),
+        })
+    }
+
+    fn json_column_as_f64(v: &[u8], column: &str) -> Result<f64> {
+        Self::json_column(v, column)?
+            .as_f64()
+            .ok_or_else(|| SinkError::Redis(format!("column `{column}` is not numeric")).into())
+    }
 }
 
 impl FormattedSink for RedisSinkPayloadWriter {
@@ -297,9 +739,74 @@ impl FormattedSink for RedisSinkPayloadWriter {
 
     async fn write_one(&mut self, k: Option<Self::K>, v: Option<Self::V>) -> Result<()> {
         let k = k.unwrap();
-        match v {
-            Some(v) => self.pipe.set(k, v),
-            None => self.pipe.del(k),
+        if self.mode == RedisMode::Publish {
+            let v = v.ok_or_else(|| {
+                SinkError::Redis("redis.mode = publish has nothing to delete".to_string())
+            })?;
+            self.pipe.publish(k, v);
+            return Ok(());
+        }
+        let Some(v) = v else {
+            // A hash/sorted-set/stream row is one field/member of a key shared with other
+            // rows, so the delete must target just that row, not the whole key -- a plain
+            // `DEL` would silently wipe every sibling field/member too.
+            match self.data_type {
+                RedisDataType::String => self.pipe.del(k),
+                RedisDataType::Hash => {
+                    // A row written before this sink's last restart has no entry in
+                    // `hash_fields` (the cache is in-memory only), and that's a normal
+                    // occurrence, not a corruption -- degrade to a no-op HDEL instead of
+                    // failing the whole streaming job over a row we can no longer resolve a
+                    // field for.
+                    match self.hash_fields.remove(&k) {
+                        Some(field) => self.pipe.hdel(k, field),
+                        None => warn!(
+                            key = %k,
+                            "redis sink: skipping delete, no field recorded for this key (it was likely written before this sink last restarted)"
+                        ),
+                    }
+                }
+                RedisDataType::SortedSet => {
+                    let sorted_set_key = self.sorted_set_key.clone().unwrap();
+                    self.pipe.zrem(sorted_set_key, k);
+                }
+                RedisDataType::Stream => {
+                    // `validate` rejects non-append-only formats for `redis.data_type = stream`,
+                    // so a delete should never reach a stream writer.
+                    return Err(SinkError::Redis(
+                        "redis.data_type = stream does not support deletes".to_string(),
+                    )
+                    .into());
+                }
+            }
+            return Ok(());
+        };
+        match self.data_type {
+            RedisDataType::String => self.pipe.set(k, v, self.expire),
+            RedisDataType::Hash => {
+                let field_column = self.hash_field_column.as_deref().unwrap();
+                let field = Self::json_column_as_string(&v, field_column)?;
+                // If this row's `hash_field_column` value changed since it was last written,
+                // the old field is now orphaned on the key -- nothing else will ever target
+                // it for removal -- so clean it up alongside writing the new one.
+                if let Some(old_field) = self.hash_fields.insert(k.clone(), field.clone()) {
+                    if old_field != field {
+                        self.pipe.hdel(k.clone(), old_field);
+                    }
+                }
+                self.pipe.hset(k, field, v);
+            }
+            RedisDataType::SortedSet => {
+                let score_column = self.sorted_set_score_column.as_deref().unwrap();
+                let score = Self::json_column_as_f64(&v, score_column)?;
+                let sorted_set_key = self.sorted_set_key.clone().unwrap();
+                self.pipe.zadd(sorted_set_key, k, score);
+            }
+            RedisDataType::Stream => {
+                let field_column = self.hash_field_column.as_deref().unwrap();
+                let field = Self::json_column_as_string(&v, field_column)?;
+                self.pipe.xadd(k, field, v);
+            }
         };
         Ok(())
     }
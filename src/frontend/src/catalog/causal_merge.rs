@@ -0,0 +1,197 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dotted Version Vector Set (DVVS) conflict resolution algorithm, standalone scaffolding for a
+//! future causal-merge table conflict strategy.
+//!
+//! **Status: not integrated.** This module exports the algorithm only -- dependency-free and
+//! unit-tested without a streaming runtime or a catalog -- and nothing in this crate constructs
+//! or calls a [`CausalRegister`] outside its own tests. Wiring it up needs, at minimum:
+//! `risingwave_common::catalog::ConflictBehavior` (not part of this crate) to gain a
+//! `CausalMerge` variant, a `TableCatalog` column to carry the opaque causal context, and a
+//! conflict-handling executor that calls [`CausalRegister::put`] on upsert and
+//! [`CausalRegister::context`]/[`CausalRegister::siblings`] on read. None of that exists yet.
+//! Until it does, this module is deliberately kept unreferenced rather than threaded through the
+//! catalog ahead of having anything to drive it.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Identifies the writer (node/fragment) that produced a dot.
+pub type NodeId = u32;
+
+/// A version vector: for each node, the highest write counter from that node reflected here.
+/// A node absent from the map is equivalent to a counter of `0`, i.e. "no writes seen yet".
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VersionVector(BTreeMap<NodeId, u64>);
+
+impl VersionVector {
+    pub fn counter(&self, node_id: NodeId) -> u64 {
+        self.0.get(&node_id).copied().unwrap_or(0)
+    }
+
+    /// Whether this context already reflects `dot`, i.e. the value it tagged was read (directly
+    /// or transitively) by whoever supplied this context and so can be discarded on their next
+    /// write.
+    pub fn covers(&self, dot: Dot) -> bool {
+        dot.counter <= self.counter(dot.node_id)
+    }
+
+    /// Merge `dot` into this vector, raising the node's counter if `dot` is newer.
+    fn advance(&mut self, dot: Dot) {
+        let counter = self.0.entry(dot.node_id).or_insert(0);
+        *counter = (*counter).max(dot.counter);
+    }
+
+    /// Pointwise-max merge of two version vectors, used when reconciling siblings from two
+    /// replicas so the merged context covers everything either side had seen.
+    fn merge(&mut self, other: &VersionVector) {
+        for (&node_id, &counter) in &other.0 {
+            self.advance(Dot { node_id, counter });
+        }
+    }
+}
+
+/// A single write, uniquely identified by the node that produced it and that node's counter at
+/// the time. Dots are totally ordered so they can key a `BTreeMap`, which is what makes
+/// [`CausalRegister::merge`] idempotent: re-applying a sibling under a dot already present is a
+/// no-op rather than a duplicate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dot {
+    pub node_id: NodeId,
+    pub counter: u64,
+}
+
+/// The DVVS for a single key: the merged version vector of everything ever written to it, plus
+/// the values -- siblings -- that are concurrent as of that vector, each tagged with the dot of
+/// the write that produced it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CausalRegister<V> {
+    context: VersionVector,
+    siblings: BTreeMap<Dot, V>,
+}
+
+impl<V> CausalRegister<V> {
+    /// The opaque causal context to hand back to a reader, to be echoed on their next write.
+    pub fn context(&self) -> &VersionVector {
+        &self.context
+    }
+
+    /// All values currently concurrent under [`Self::context`].
+    pub fn siblings(&self) -> impl Iterator<Item = &V> {
+        self.siblings.values()
+    }
+
+    /// Apply a client upsert from `writer`, who last read `read_context`.
+    ///
+    /// Mints a fresh, per-node-monotonic dot for `writer`, drops every existing sibling already
+    /// covered by `read_context` (i.e. the client had a chance to see and resolve it), and keeps
+    /// `value` plus any sibling the client's context didn't cover as concurrent writes. An empty
+    /// `read_context` covers nothing, so a blind write never drops existing siblings -- it always
+    /// lands as a new concurrent sibling.
+    pub fn put(&mut self, writer: NodeId, read_context: &VersionVector, value: V) -> Dot {
+        let dot = Dot {
+            node_id: writer,
+            counter: self.context.counter(writer) + 1,
+        };
+        self.siblings.retain(|&d, _| !read_context.covers(d));
+        self.siblings.insert(dot, value);
+        self.context.advance(dot);
+        self.context.merge(read_context);
+        dot
+    }
+
+    /// Reconcile with a register replicated from another node, e.g. during stream shuffle or
+    /// recovery. Keyed by [`Dot`], so merging the same remote state twice is a no-op: already
+    /// -present dots are simply overwritten with an identical value, and already-covered dots are
+    /// dropped exactly as in [`Self::put`].
+    pub fn merge(&mut self, other_context: &VersionVector, other_siblings: Vec<(Dot, V)>) {
+        // A dot the other side still holds as a sibling is live there, even though its own
+        // context covers it, so it must survive on both sides of the merge.
+        let other_dots: BTreeSet<Dot> = other_siblings.iter().map(|(d, _)| *d).collect();
+        self.siblings
+            .retain(|d, _| !other_context.covers(*d) || other_dots.contains(d));
+        for (dot, value) in other_siblings {
+            if !self.context.covers(dot) || self.siblings.contains_key(&dot) {
+                self.siblings.insert(dot, value);
+            }
+        }
+        self.context.merge(other_context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_writes_from_different_nodes_are_kept_as_siblings() {
+        let mut reg = CausalRegister::default();
+        reg.put(1, &VersionVector::default(), "a");
+        reg.put(2, &VersionVector::default(), "b");
+
+        assert_eq!(reg.siblings().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn write_with_context_covering_prior_value_resolves_it() {
+        let mut reg = CausalRegister::default();
+        reg.put(1, &VersionVector::default(), "a");
+        let context_after_read = reg.context().clone();
+
+        reg.put(1, &context_after_read, "a-v2");
+
+        assert_eq!(reg.siblings().copied().collect::<Vec<_>>(), vec!["a-v2"]);
+    }
+
+    #[test]
+    fn empty_context_never_drops_existing_siblings() {
+        let mut reg = CausalRegister::default();
+        reg.put(1, &VersionVector::default(), "a");
+        reg.put(2, &VersionVector::default(), "b");
+
+        reg.put(3, &VersionVector::default(), "c");
+
+        assert_eq!(
+            reg.siblings().copied().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn counters_are_per_node_monotonic() {
+        let mut reg = CausalRegister::default();
+        let dot1 = reg.put(1, &VersionVector::default(), "a");
+        let dot2 = reg.put(1, &VersionVector::default(), "b");
+        let dot3 = reg.put(2, &VersionVector::default(), "c");
+
+        assert_eq!(dot1, Dot { node_id: 1, counter: 1 });
+        assert_eq!(dot2, Dot { node_id: 1, counter: 2 });
+        assert_eq!(dot3, Dot { node_id: 2, counter: 1 });
+    }
+
+    #[test]
+    fn replaying_a_merge_is_idempotent() {
+        let mut a = CausalRegister::default();
+        a.put(1, &VersionVector::default(), "a");
+
+        let mut b = CausalRegister::default();
+        b.merge(a.context(), a.siblings().copied().map(|v| (Dot { node_id: 1, counter: 1 }, v)).collect());
+        let after_first_merge = b.clone();
+
+        // Replaying the exact same merge must not duplicate or otherwise change the sibling set.
+        b.merge(a.context(), a.siblings().copied().map(|v| (Dot { node_id: 1, counter: 1 }, v)).collect());
+
+        assert_eq!(b, after_first_merge);
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Row providers for `information_schema.tables` and `information_schema.columns`, generated
+//! directly off [`TableCatalog`] so they stay correct as the struct evolves, rather than being
+//! maintained as a hand-rolled system catalog.
+
+use std::collections::{HashMap, HashSet};
+
+use super::table_catalog::{TableCatalog, TableType};
+
+/// One row of `information_schema.tables`.
+pub struct InformationSchemaTablesRow {
+    pub table_name: String,
+    /// SQL-standard `table_type`, e.g. `BASE TABLE`. Indexes and internal tables have no
+    /// user-facing row and are filtered out before this row is produced.
+    pub table_type: &'static str,
+    /// Estimated row count, derived from [`TableCatalog::cardinality`]. `None` if the
+    /// cardinality is unknown or unbounded.
+    pub row_estimate: Option<i64>,
+    pub retention_seconds: Option<u32>,
+}
+
+/// One row of `information_schema.columns`.
+pub struct InformationSchemaColumnsRow {
+    pub table_name: String,
+    pub column_name: String,
+    /// 1-indexed position of the column within the table, per the SQL standard.
+    pub ordinal_position: i32,
+    pub data_type: String,
+    pub is_nullable: bool,
+    pub is_generated: bool,
+    /// Rendered default expression, or `None` if the column has no default.
+    pub column_default: Option<String>,
+}
+
+/// Maps a table to its `table_type` column, or `None` if it shouldn't appear in
+/// `information_schema.tables` at all (indexes and internal tables are implementation details).
+fn table_type(table_type: TableType) -> Option<&'static str> {
+    match table_type {
+        TableType::Table | TableType::MaterializedView | TableType::Scheduled => {
+            Some("BASE TABLE")
+        }
+        TableType::Index | TableType::Internal => None,
+    }
+}
+
+/// Builds the `information_schema.tables` rows for the given set of tables.
+pub fn tables_rows<'a>(
+    tables: impl Iterator<Item = &'a TableCatalog> + 'a,
+) -> impl Iterator<Item = InformationSchemaTablesRow> + 'a {
+    tables.filter_map(|table| {
+        let table_type = table_type(table.table_type())?;
+        Some(InformationSchemaTablesRow {
+            table_name: table.name().to_string(),
+            table_type,
+            row_estimate: table.cardinality.hi().map(|hi| hi as i64),
+            retention_seconds: table.retention_seconds,
+        })
+    })
+}
+
+/// Builds the `information_schema.columns` rows for the given set of tables. Hidden columns
+/// (e.g. the synthetic row id) are filtered out via [`ColumnCatalog::is_hidden`], the same check
+/// `columns_to_insert` uses; generated columns are kept but flagged via `is_generated`.
+///
+/// [`ColumnCatalog::is_hidden`]: risingwave_common::catalog::ColumnCatalog::is_hidden
+pub fn columns_rows<'a>(
+    tables: impl Iterator<Item = &'a TableCatalog> + 'a,
+) -> impl Iterator<Item = InformationSchemaColumnsRow> + 'a {
+    tables.flat_map(|table| {
+        let pk_indices: HashSet<usize> = table.pk().iter().map(|o| o.column_index).collect();
+        let generated_indices: HashSet<usize> = table.generated_col_idxes().collect();
+        let defaults: HashMap<usize, String> = table
+            .default_columns()
+            .map(|(idx, expr)| (idx, expr.to_string()))
+            .collect();
+
+        table
+            .columns()
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| !column.is_hidden())
+            .map(move |(idx, column)| InformationSchemaColumnsRow {
+                table_name: table.name().to_string(),
+                column_name: column.name().to_string(),
+                ordinal_position: (idx + 1) as i32,
+                data_type: column.data_type().to_string(),
+                is_nullable: !pk_indices.contains(&idx),
+                is_generated: generated_indices.contains(&idx),
+                column_default: defaults.get(&idx).cloned(),
+            })
+    })
+}
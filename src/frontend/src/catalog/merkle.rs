@@ -0,0 +1,174 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A balanced binary Merkle tree over per-vnode content digests, used to cheaply detect and
+//! localize content divergence between a table and its backup/standby (see
+//! [`TableVersion::content_digest`](super::table_catalog::TableVersion)).
+//!
+//! Leaves are the digest of each vnode's committed state at an epoch, built from
+//! [`hash_sorted_keys`] so the result is independent of scan order. Parents hash the
+//! concatenation of their two children, duplicating the last node up a level when a level has an
+//! odd count. [`MerkleTree::diverging_vnodes`] is the full localization walk: given the trees from
+//! both sides it returns exactly the vnodes whose leaf digest differs.
+//!
+//! What this module does *not* provide is the admin-facing recompute/compare call the divergence
+//! check is meant to back: building a fresh [`MerkleTree`] requires scanning every vnode's
+//! committed state, which is the storage layer's job, and there is no storage access in this
+//! pruned catalog crate to drive it from here. `TableVersion::content_digest` only stores the
+//! already-computed root; an admin call would need to (1) ask storage to recompute the live tree
+//! on each side, (2) compare roots, and, on mismatch, (3) call [`MerkleTree::diverging_vnodes`] on
+//! the two trees -- step 3 is implemented and tested below, steps 1 and 2 are not.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A balanced binary Merkle tree over per-vnode digests.
+///
+/// `layers[0]` holds one digest per vnode, in vnode order; each subsequent layer is half the
+/// size of the one below (rounded up), and `layers.last()` is the single-element root layer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    layers: Vec<Vec<u64>>,
+}
+
+impl MerkleTree {
+    /// Builds the tree bottom-up from one digest per vnode. Panics if `leaves` is empty.
+    pub fn build(leaves: Vec<u64>) -> Self {
+        assert!(!leaves.is_empty(), "a table always has at least one vnode");
+
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let below = layers.last().unwrap();
+            let above = below
+                .chunks(2)
+                .map(|pair| hash_pair(pair[0], *pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            layers.push(above);
+        }
+        Self { layers }
+    }
+
+    /// The root hash, to be stored as `TableVersion::content_digest` and compared across
+    /// replicas to detect divergence.
+    pub fn root(&self) -> u64 {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// The number of vnode leaves this tree was built from.
+    pub fn leaf_count(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Walks both trees down from the root, skipping any subtree whose hash already matches, and
+    /// returns the vnode indices whose leaf digest differs. Empty iff the roots match.
+    ///
+    /// Panics if `self` and `other` were not built from the same number of vnodes.
+    pub fn diverging_vnodes(&self, other: &MerkleTree) -> Vec<usize> {
+        assert_eq!(
+            self.leaf_count(),
+            other.leaf_count(),
+            "can only compare trees built over the same vnode count"
+        );
+
+        let mut out = Vec::new();
+        if self.root() != other.root() {
+            self.collect_diverging(other, self.layers.len() - 1, 0, &mut out);
+        }
+        out
+    }
+
+    fn collect_diverging(&self, other: &Self, layer: usize, pos: usize, out: &mut Vec<usize>) {
+        if layer == 0 {
+            out.push(pos);
+            return;
+        }
+
+        let below = layer - 1;
+        let left = 2 * pos;
+        let right = (2 * pos + 1).min(self.layers[below].len() - 1);
+
+        if self.layers[below][left] != other.layers[below][left] {
+            self.collect_diverging(other, below, left, out);
+        }
+        if right != left && self.layers[below][right] != other.layers[below][right] {
+            self.collect_diverging(other, below, right, out);
+        }
+    }
+}
+
+fn hash_pair(left: u64, right: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    left.hash(&mut hasher);
+    right.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes a vnode's keys into a single leaf digest, independent of the order they're scanned in:
+/// the caller's keys are sorted before being fed to the hasher one at a time.
+pub fn hash_sorted_keys<K: Ord + Hash>(keys: &mut [K]) -> u64 {
+    keys.sort();
+    let mut hasher = DefaultHasher::new();
+    for key in keys.iter() {
+        key.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_is_deterministic_regardless_of_leaf_count_parity() {
+        let even = MerkleTree::build(vec![1, 2, 3, 4]);
+        let odd = MerkleTree::build(vec![1, 2, 3]);
+        // Just asserting both build without panicking and produce stable roots.
+        assert_eq!(even.root(), MerkleTree::build(vec![1, 2, 3, 4]).root());
+        assert_eq!(odd.root(), MerkleTree::build(vec![1, 2, 3]).root());
+    }
+
+    #[test]
+    fn matching_leaves_produce_matching_roots() {
+        let a = MerkleTree::build(vec![10, 20, 30, 40]);
+        let b = MerkleTree::build(vec![10, 20, 30, 40]);
+        assert_eq!(a.root(), b.root());
+        assert!(a.diverging_vnodes(&b).is_empty());
+    }
+
+    #[test]
+    fn a_single_changed_leaf_is_localized() {
+        let a = MerkleTree::build(vec![10, 20, 30, 40]);
+        let b = MerkleTree::build(vec![10, 20, 99, 40]);
+
+        assert_ne!(a.root(), b.root());
+        assert_eq!(a.diverging_vnodes(&b), vec![2]);
+    }
+
+    #[test]
+    fn multiple_changed_leaves_are_all_localized() {
+        let a = MerkleTree::build(vec![10, 20, 30, 40, 50]);
+        let b = MerkleTree::build(vec![10, 99, 30, 98, 50]);
+
+        let mut diverging = a.diverging_vnodes(&b);
+        diverging.sort_unstable();
+        assert_eq!(diverging, vec![1, 3]);
+    }
+
+    #[test]
+    fn hash_sorted_keys_is_order_independent() {
+        let mut forward = vec!["c", "a", "b"];
+        let mut backward = vec!["b", "c", "a"];
+        assert_eq!(hash_sorted_keys(&mut forward), hash_sorted_keys(&mut backward));
+    }
+}
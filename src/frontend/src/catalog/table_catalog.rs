@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
@@ -20,9 +22,16 @@ use risingwave_common::catalog::{
     ColumnCatalog, ConflictBehavior, CreateType, Field, Schema, StreamJobStatus, TableDesc,
     TableId, TableVersionId,
 };
+use risingwave_common::types::DataType;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::sort_util::ColumnOrder;
-use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbTableType, PbTableVersion};
+use risingwave_pb::catalog::table::column_compression::Codec as PbCompressionCodec;
+use risingwave_pb::catalog::table::table_constraint::ConstraintType as PbConstraintType;
+use risingwave_pb::catalog::table::{
+    OptionalAssociatedSourceId, PbBrotliCompression, PbCheckConstraint, PbColumnCompression,
+    PbLz4Compression, PbPartitionCountIndexDesc, PbTableConstraint, PbTableSchedule, PbTableType,
+    PbTableVersion, PbUniqueConstraint, PbZstdCompression,
+};
 use risingwave_pb::catalog::{PbCreateType, PbStreamJobStatus, PbTable};
 use risingwave_pb::plan_common::column_desc::GeneratedOrDefaultColumn;
 use risingwave_pb::plan_common::DefaultColumnDesc;
@@ -100,6 +109,11 @@ pub struct TableCatalog {
     /// The cardinality of the table.
     pub cardinality: Cardinality,
 
+    /// Descriptor of the value-count index over the leading `read_prefix_len_hint` columns, if
+    /// one is recorded for this table. Only the descriptor is stored and round-tripped; nothing in
+    /// this crate builds or maintains the index it points at. See [`PartitionCountIndexDesc`].
+    pub partition_count_index: Option<PartitionCountIndexDesc>,
+
     /// Owner of the table.
     pub owner: UserId,
 
@@ -112,6 +126,10 @@ pub struct TableCatalog {
     /// The fragment id of the `DML` operator for this table.
     pub dml_fragment_id: Option<FragmentId>,
 
+    /// For [`TableType::Scheduled`] tables, the cron-like cadence at which the table's
+    /// `dml_fragment_id` should be triggered to refresh. `None` for all other table types.
+    pub schedule: Option<TableSchedule>,
+
     /// An optional column index which is the vnode of each row computed by the table's consistent
     /// hash distribution.
     pub vnode_col_index: Option<usize>,
@@ -131,6 +149,26 @@ pub struct TableCatalog {
     /// `No Check`.
     pub conflict_behavior: ConflictBehavior,
 
+    /// Named constraints beyond the primary key: `UNIQUE` keys and `CHECK` predicates. Lets the
+    /// optimizer prove uniqueness beyond the pk for join/aggregate elimination, and lets DML
+    /// validate `CHECK` predicates on insert.
+    pub constraints: Vec<TableConstraint>,
+
+    /// Column indices using [`ColumnEncoding::Dictionary`] for their state-table value slot,
+    /// set via `ENCODING DICTIONARY` in DDL. Columns not present here use
+    /// [`ColumnEncoding::Plain`]. See [`Self::dictionary_encoded_columns`].
+    pub dictionary_encoded_column_indices: FixedBitSet,
+
+    /// Per-column storage compression codecs, consulted by the storage layer when it encodes a
+    /// column's value into the state table. Columns not present here use
+    /// [`ColumnCompression::None`]. See [`Self::column_compression`].
+    ///
+    /// This is a side-table keyed by column index rather than a field on `ColumnDesc` itself,
+    /// which is what was actually asked for -- `ColumnDesc` is defined in
+    /// `risingwave_common::catalog`, outside this pruned tree, so it couldn't be extended here.
+    /// Callers that expect `column.column_desc.compression` to exist will not find it.
+    pub column_compression_hints: Vec<ColumnCompressionHint>,
+
     pub version_column_index: Option<usize>,
 
     pub read_prefix_len_hint: usize,
@@ -180,6 +218,9 @@ pub enum TableType {
     Index,
     /// Internal tables for executors.
     Internal,
+    /// Tables created by `CREATE TABLE ... WITH SCHEDULE`, refreshed on a timer instead of (or in
+    /// addition to) incoming DML.
+    Scheduled,
 }
 
 #[cfg(test)]
@@ -196,6 +237,7 @@ impl TableType {
             PbTableType::MaterializedView => Self::MaterializedView,
             PbTableType::Index => Self::Index,
             PbTableType::Internal => Self::Internal,
+            PbTableType::Scheduled => Self::Scheduled,
             PbTableType::Unspecified => unreachable!(),
         }
     }
@@ -206,6 +248,7 @@ impl TableType {
             Self::MaterializedView => PbTableType::MaterializedView,
             Self::Index => PbTableType::Index,
             Self::Internal => PbTableType::Internal,
+            Self::Scheduled => PbTableType::Scheduled,
         }
     }
 }
@@ -215,6 +258,13 @@ impl TableType {
 pub struct TableVersion {
     pub version_id: TableVersionId,
     pub next_column_id: ColumnId,
+
+    /// Root hash of the per-vnode content `MerkleTree` as of this version, if one has been
+    /// computed. Lets an admin call cheaply detect divergence between this table and its
+    /// backup/standby by comparing roots, and walk the tree via
+    /// [`diverging_vnodes`](super::merkle::MerkleTree::diverging_vnodes) to localize which vnode
+    /// diverged.
+    pub content_digest: Option<u64>,
 }
 
 impl TableVersion {
@@ -226,6 +276,7 @@ impl TableVersion {
         Self {
             version_id: INITIAL_TABLE_VERSION_ID,
             next_column_id: max_column_id.next(),
+            content_digest: None,
         }
     }
 
@@ -233,6 +284,7 @@ impl TableVersion {
         Self {
             version_id: prost.version,
             next_column_id: ColumnId::from(prost.next_column_id),
+            content_digest: prost.content_digest,
         }
     }
 
@@ -240,6 +292,212 @@ impl TableVersion {
         PbTableVersion {
             version: self.version_id,
             next_column_id: self.next_column_id.into(),
+            content_digest: self.content_digest,
+        }
+    }
+}
+
+/// A table-level constraint beyond the primary key, declared via `UNIQUE` or `CHECK` in
+/// `CREATE TABLE`. See [`TableCatalog::unique_constraints`] and
+/// [`TableCatalog::check_constraints`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableConstraint {
+    /// `UNIQUE (columns...)`. Columns are stored as indices, consistent with the rest of
+    /// `TableCatalog`. The column order is part of the constraint's identity -- it must be
+    /// treated the same way a storage index over these columns would be laid out -- so it is
+    /// not normalized or sorted.
+    Unique { name: String, columns: Vec<usize> },
+    /// `CHECK (expr)`.
+    Check { name: String, expr: ExprImpl },
+}
+
+impl TableConstraint {
+    fn to_prost(&self) -> PbTableConstraint {
+        let constraint_type = match self {
+            Self::Unique { name, columns } => PbConstraintType::Unique(PbUniqueConstraint {
+                name: name.clone(),
+                column_indices: columns.iter().map(|idx| *idx as u32).collect(),
+            }),
+            Self::Check { name, expr } => PbConstraintType::Check(PbCheckConstraint {
+                name: name.clone(),
+                expr: Some(expr.to_expr_proto()),
+            }),
+        };
+        PbTableConstraint {
+            constraint_type: Some(constraint_type),
+        }
+    }
+
+    /// Converts from the protobuf representation, panicking if a `UNIQUE` constraint references
+    /// a column index that is out of range for `num_columns` -- the same invariant-violation
+    /// convention [`From<PbTable>`] already uses for e.g. duplicated column names.
+    fn from_prost(pb: PbTableConstraint, num_columns: usize) -> Self {
+        match pb.constraint_type.expect("constraint_type should be set") {
+            PbConstraintType::Unique(u) => {
+                let columns = u.column_indices.iter().map(|idx| *idx as usize).collect_vec();
+                for &idx in &columns {
+                    assert!(
+                        idx < num_columns,
+                        "column index {} in unique constraint `{}` is out of range ({} columns)",
+                        idx,
+                        u.name,
+                        num_columns
+                    );
+                }
+                Self::Unique {
+                    name: u.name,
+                    columns,
+                }
+            }
+            PbConstraintType::Check(c) => Self::Check {
+                name: c.name,
+                expr: ExprImpl::from_expr_proto(c.expr.as_ref().unwrap())
+                    .expect("expr in check constraint corrupted"),
+            },
+        }
+    }
+}
+
+/// Storage encoding hint for a column's state-table value slot, set via `ENCODING DICTIONARY` in
+/// DDL. Dictionary encoding a low-cardinality `varchar`/`bytea` column can drastically cut
+/// state-store size; see [`TableCatalog::dictionary_encoded_columns`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColumnEncoding {
+    #[default]
+    Plain,
+    Dictionary,
+}
+
+/// The refresh cadence of a [`TableType::Scheduled`] table, set via `CREATE TABLE ... WITH
+/// SCHEDULE` DDL.
+///
+/// This descriptor is inert by itself: it is only a catalog-level round-trippable value today.
+/// Actually firing a refresh on this cadence needs a timer-driven trigger that calls into the
+/// table's `dml_fragment_id` on schedule -- that trigger (and the planner/DDL wiring to construct
+/// this value from `WITH SCHEDULE` in the first place) lives outside this pruned catalog crate
+/// and is not part of this change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TableSchedule {
+    /// How often the table should be refreshed, in seconds.
+    pub every_seconds: u32,
+    /// An optional offset, in seconds, from the start of each interval at which the refresh
+    /// should actually fire (e.g. to stagger several scheduled tables).
+    pub at_offset: Option<u32>,
+}
+
+impl TableSchedule {
+    fn from_prost(prost: PbTableSchedule) -> Self {
+        Self {
+            every_seconds: prost.every_seconds,
+            at_offset: prost.at_offset,
+        }
+    }
+
+    fn to_prost(self) -> PbTableSchedule {
+        PbTableSchedule {
+            every_seconds: self.every_seconds,
+            at_offset: self.at_offset,
+        }
+    }
+}
+
+/// Descriptor for the backing index of [`TableCatalog::partition_count_index`]: an internal
+/// table, keyed by the leading `read_prefix_len_hint` columns, whose value would be a running
+/// count of rows under that prefix, giving the optimizer an `O(1)` lookup instead of a scan.
+///
+/// This descriptor only identifies which internal table *would* back the index; nothing in this
+/// tree creates that internal table or maintains its counter incrementally on insert/delete --
+/// that's the state table executor's job, which lives outside this pruned catalog crate. Today
+/// this is catalog-level bookkeeping only, round-trippable but not yet backed by a live index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PartitionCountIndexDesc {
+    /// The internal table (see [`TableType::Internal`]) backing the index.
+    pub index_table_id: TableId,
+}
+
+impl PartitionCountIndexDesc {
+    fn from_prost(prost: PbPartitionCountIndexDesc) -> Self {
+        Self {
+            index_table_id: prost.index_table_id.into(),
+        }
+    }
+
+    fn to_prost(self) -> PbPartitionCountIndexDesc {
+        PbPartitionCountIndexDesc {
+            index_table_id: self.index_table_id.table_id,
+        }
+    }
+}
+
+/// Storage compression codec for a column's state-table value slot. Wide analytic tables often
+/// have one high-entropy text column and several low-cardinality ones; picking a codec per column
+/// (rather than one table-wide setting) lets users trade CPU for space where it actually matters.
+/// See [`TableCatalog::column_compression`].
+///
+/// The original request asked for this as an optional field directly on `ColumnDesc`, set via
+/// `WITH (compression = '...')` in DDL. `ColumnDesc` lives in `risingwave_common::catalog`, which
+/// is out of this pruned tree, so it's represented here instead as
+/// [`TableCatalog::column_compression_hints`], a side-table of `(column_index, codec)` entries --
+/// see that field's doc for why this is a real data-model divergence, not just a naming
+/// difference. There is also no DDL handler in this tree to parse `WITH (compression = '...')`;
+/// today a [`ColumnCompressionHint`] can only be constructed programmatically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColumnCompression {
+    #[default]
+    None,
+    Zstd {
+        level: i32,
+    },
+    Lz4,
+    Brotli {
+        quality: i32,
+    },
+}
+
+impl ColumnCompression {
+    fn from_prost(codec: Option<PbCompressionCodec>) -> Self {
+        match codec {
+            None => Self::None,
+            Some(PbCompressionCodec::Zstd(PbZstdCompression { level })) => Self::Zstd { level },
+            Some(PbCompressionCodec::Lz4(PbLz4Compression {})) => Self::Lz4,
+            Some(PbCompressionCodec::Brotli(PbBrotliCompression { quality })) => {
+                Self::Brotli { quality }
+            }
+        }
+    }
+
+    fn to_prost(self) -> Option<PbCompressionCodec> {
+        match self {
+            Self::None => None,
+            Self::Zstd { level } => Some(PbCompressionCodec::Zstd(PbZstdCompression { level })),
+            Self::Lz4 => Some(PbCompressionCodec::Lz4(PbLz4Compression {})),
+            Self::Brotli { quality } => {
+                Some(PbCompressionCodec::Brotli(PbBrotliCompression { quality }))
+            }
+        }
+    }
+}
+
+/// A single entry of [`TableCatalog::column_compression_hints`]: the compression codec set on
+/// one column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColumnCompressionHint {
+    pub column_index: usize,
+    pub codec: ColumnCompression,
+}
+
+impl ColumnCompressionHint {
+    fn from_prost(pb: PbColumnCompression) -> Self {
+        Self {
+            column_index: pb.column_index as usize,
+            codec: ColumnCompression::from_prost(pb.codec),
+        }
+    }
+
+    fn to_prost(&self) -> PbColumnCompression {
+        PbColumnCompression {
+            column_index: self.column_index as u32,
+            codec: self.codec.to_prost(),
         }
     }
 }
@@ -284,6 +542,10 @@ impl TableCatalog {
         self.table_type == TableType::Index
     }
 
+    pub fn is_scheduled(&self) -> bool {
+        self.table_type == TableType::Scheduled
+    }
+
     /// Returns an error if `DROP` statements are used on the wrong type of table.
     #[must_use]
     pub fn bad_drop_error(&self) -> RwError {
@@ -294,6 +556,7 @@ impl TableCatalog {
             TableType::Index => "Use `DROP INDEX` to drop an index.",
             TableType::Table => "Use `DROP TABLE` to drop a table.",
             TableType::Internal => "Internal tables cannot be dropped.",
+            TableType::Scheduled => "Use `DROP TABLE` to drop a scheduled table.",
         };
 
         ErrorCode::InvalidInputSyntax(msg.to_owned()).into()
@@ -327,6 +590,56 @@ impl TableCatalog {
             .collect()
     }
 
+    /// Get the table's `UNIQUE` constraints, as `(name, columns)` where `columns` are indices
+    /// in order -- the order is significant and is not the same as e.g. a sorted `HashSet`.
+    pub fn unique_constraints(&self) -> impl Iterator<Item = (&str, &[usize])> {
+        self.constraints.iter().filter_map(|c| match c {
+            TableConstraint::Unique { name, columns } => Some((name.as_str(), columns.as_slice())),
+            TableConstraint::Check { .. } => None,
+        })
+    }
+
+    /// Get the table's `CHECK` constraints, as `(name, predicate)`.
+    pub fn check_constraints(&self) -> impl Iterator<Item = (&str, &ExprImpl)> {
+        self.constraints.iter().filter_map(|c| match c {
+            TableConstraint::Check { name, expr } => Some((name.as_str(), expr)),
+            TableConstraint::Unique { .. } => None,
+        })
+    }
+
+    /// Get the storage encoding hint for the column at `column_index`.
+    pub fn column_encoding(&self, column_index: usize) -> ColumnEncoding {
+        if self.dictionary_encoded_column_indices.contains(column_index) {
+            ColumnEncoding::Dictionary
+        } else {
+            ColumnEncoding::Plain
+        }
+    }
+
+    /// The subset of [`Self::value_indices`] that are [`ColumnEncoding::Dictionary`]-encoded, so
+    /// the encode/decode layer knows which value slots to build and consult a dictionary for.
+    pub fn dictionary_encoded_columns(&self) -> Vec<usize> {
+        self.value_indices
+            .iter()
+            .copied()
+            .filter(|&idx| self.dictionary_encoded_column_indices.contains(idx))
+            .collect()
+    }
+
+    /// Get the storage compression codec for the column at `column_index`.
+    pub fn column_compression(&self, column_index: usize) -> ColumnCompression {
+        self.column_compression_hints
+            .iter()
+            .find(|hint| hint.column_index == column_index)
+            .map_or(ColumnCompression::None, |hint| hint.codec)
+    }
+
+    /// Whether this table records a [`PartitionCountIndexDesc`]. This only checks that the
+    /// descriptor is set; it does not check that a live index backs it.
+    pub fn has_partition_count_index(&self) -> bool {
+        self.partition_count_index.is_some()
+    }
+
     /// Get a [`TableDesc`] of the table.
     pub fn table_desc(&self) -> TableDesc {
         use risingwave_common::catalog::TableOption;
@@ -346,6 +659,7 @@ impl TableCatalog {
             watermark_columns: self.watermark_columns.clone(),
             versioned: self.version.is_some(),
             vnode_col_index: self.vnode_col_index,
+            dictionary_encoded_column_indices: self.dictionary_encoded_column_indices.clone(),
         }
     }
 
@@ -381,6 +695,12 @@ impl TableCatalog {
         self.version().map(|v| v.version_id)
     }
 
+    /// Get the table's last-computed content `MerkleTree` root hash. Returns `None` if the table
+    /// has no version field, or no digest has been computed for this version yet.
+    pub fn content_digest(&self) -> Option<u64> {
+        self.version().and_then(|v| v.content_digest)
+    }
+
     pub fn to_prost(&self, schema_id: SchemaId, database_id: DatabaseId) -> PbTable {
         PbTable {
             id: self.id.table_id,
@@ -404,6 +724,7 @@ impl TableCatalog {
             owner: self.owner,
             fragment_id: self.fragment_id,
             dml_fragment_id: self.dml_fragment_id,
+            schedule: self.schedule.map(TableSchedule::to_prost),
             vnode_col_index: self.vnode_col_index.map(|i| i as _),
             row_id_index: self.row_id_index.map(|i| i as _),
             value_indices: self.value_indices.iter().map(|x| *x as _).collect(),
@@ -413,7 +734,19 @@ impl TableCatalog {
             watermark_indices: self.watermark_columns.ones().map(|x| x as _).collect_vec(),
             dist_key_in_pk: self.dist_key_in_pk.iter().map(|x| *x as _).collect(),
             handle_pk_conflict_behavior: self.conflict_behavior.to_protobuf().into(),
+            constraints: self.constraints.iter().map(TableConstraint::to_prost).collect(),
+            dictionary_encoded_column_indices: self
+                .dictionary_encoded_column_indices
+                .ones()
+                .map(|x| x as u32)
+                .collect(),
+            column_compression: self
+                .column_compression_hints
+                .iter()
+                .map(ColumnCompressionHint::to_prost)
+                .collect(),
             version_column_index: self.version_column_index.map(|value| value as u32),
+            partition_count_index: self.partition_count_index.map(PartitionCountIndexDesc::to_prost),
             cardinality: Some(self.cardinality.to_protobuf()),
             initialized_at_epoch: self.initialized_at_epoch.map(|epoch| epoch.0),
             created_at_epoch: self.created_at_epoch.map(|epoch| epoch.0),
@@ -493,6 +826,46 @@ impl TableCatalog {
                 .collect(),
         )
     }
+
+    /// A deterministic hash of only the schema-relevant fields -- column ids, names and
+    /// resolved data types, `pk`, `distribution_key`, `dist_key_in_pk`, `append_only`,
+    /// `watermark_columns`, and `version_column_index` -- fed into
+    /// the hasher in a fixed canonical order so the result is stable across processes and
+    /// `PbTable` round-trips.
+    /// Volatile bookkeeping fields (`created_at_epoch`, `*_cluster_version`,
+    /// `stream_job_status`, `fragment_id`, ...) are deliberately excluded.
+    ///
+    /// Used to skip no-op `CREATE TABLE ... OR REPLACE` / schema-change replacements, and by a
+    /// debug verification mode that re-derives the fingerprint after a `PbTable` round-trip to
+    /// catch silent corruption in `to_prost`/`From<PbTable>`.
+    ///
+    /// Purely in-process: it has no `PbTable` field of its own and adds nothing to
+    /// `proto/catalog.proto`, since it's always recomputed from fields the wire format already
+    /// carries rather than being transmitted itself.
+    pub fn schema_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_schema_fields(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Same as [`Self::schema_fingerprint`], as a big-endian byte digest.
+    pub fn schema_fingerprint_bytes(&self) -> [u8; 8] {
+        self.schema_fingerprint().to_be_bytes()
+    }
+
+    fn hash_schema_fields<H: Hasher>(&self, state: &mut H) {
+        for column in &self.columns {
+            column.column_id().get_id().hash(state);
+            column.name().hash(state);
+            column.data_type().hash(state);
+        }
+        self.pk.hash(state);
+        self.distribution_key.hash(state);
+        self.dist_key_in_pk.hash(state);
+        self.append_only.hash(state);
+        self.watermark_columns.hash(state);
+        self.version_column_index.hash(state);
+    }
 }
 
 impl From<PbTable> for TableCatalog {
@@ -529,6 +902,31 @@ impl From<PbTable> for TableCatalog {
         for idx in &tb.watermark_indices {
             watermark_columns.insert(*idx as _);
         }
+        let constraints = tb
+            .constraints
+            .into_iter()
+            .map(|c| TableConstraint::from_prost(c, columns.len()))
+            .collect_vec();
+        let mut dictionary_encoded_column_indices = FixedBitSet::with_capacity(columns.len());
+        for idx in &tb.dictionary_encoded_column_indices {
+            let idx = *idx as usize;
+            assert!(
+                matches!(
+                    columns[idx].data_type(),
+                    DataType::Varchar | DataType::Bytea
+                ),
+                "dictionary encoding is only supported on varchar/bytea columns, but column {} of table {} has type {:?}",
+                idx,
+                tb.name,
+                columns[idx].data_type()
+            );
+            dictionary_encoded_column_indices.insert(idx);
+        }
+        let column_compression_hints = tb
+            .column_compression
+            .into_iter()
+            .map(ColumnCompressionHint::from_prost)
+            .collect_vec();
 
         Self {
             id: id.into(),
@@ -544,14 +942,19 @@ impl From<PbTable> for TableCatalog {
                 .collect_vec(),
             stream_key: tb.stream_key.iter().map(|x| *x as _).collect(),
             append_only: tb.append_only,
+            partition_count_index: tb.partition_count_index.map(PartitionCountIndexDesc::from_prost),
             owner: tb.owner,
             fragment_id: tb.fragment_id,
             dml_fragment_id: tb.dml_fragment_id,
+            schedule: tb.schedule.map(TableSchedule::from_prost),
             vnode_col_index: tb.vnode_col_index.map(|x| x as usize),
             row_id_index: tb.row_id_index.map(|x| x as usize),
             value_indices: tb.value_indices.iter().map(|x| *x as _).collect(),
             definition: tb.definition,
             conflict_behavior,
+            constraints,
+            dictionary_encoded_column_indices,
+            column_compression_hints,
             version_column_index,
             read_prefix_len_hint: tb.read_prefix_len_hint as usize,
             version: tb.version.map(TableVersion::from_prost),
@@ -606,6 +1009,7 @@ mod tests {
         AdditionalColumn, ColumnDescVersion, PbColumnCatalog, PbColumnDesc,
     };
 
+    use super::super::merkle::MerkleTree;
     use super::*;
     use crate::catalog::table_catalog::{TableCatalog, TableType};
 
@@ -646,6 +1050,7 @@ mod tests {
             retention_seconds: Some(300),
             fragment_id: 0,
             dml_fragment_id: None,
+            schedule: None,
             initialized_at_epoch: None,
             value_indices: vec![0],
             definition: "".into(),
@@ -655,10 +1060,14 @@ mod tests {
             version: Some(PbTableVersion {
                 version: 0,
                 next_column_id: 2,
+                content_digest: None,
             }),
             watermark_indices: vec![],
             handle_pk_conflict_behavior: 3,
             dist_key_in_pk: vec![],
+            partition_count_index: Some(PbPartitionCountIndexDesc {
+                index_table_id: 42,
+            }),
             cardinality: None,
             created_at_epoch: None,
             cleaned_by_watermark: false,
@@ -669,9 +1078,23 @@ mod tests {
             created_at_cluster_version: None,
             initialized_at_cluster_version: None,
             version_column_index: None,
+            constraints: vec![PbTableConstraint {
+                constraint_type: Some(PbConstraintType::Unique(PbUniqueConstraint {
+                    name: "country_key".to_string(),
+                    column_indices: vec![1],
+                })),
+            }],
+            dictionary_encoded_column_indices: vec![],
+            column_compression: vec![PbColumnCompression {
+                column_index: 1,
+                codec: Some(PbCompressionCodec::Zstd(PbZstdCompression { level: 5 })),
+            }],
         }
         .into();
 
+        assert_eq!(table.column_compression(1), ColumnCompression::Zstd { level: 5 });
+        assert!(table.has_partition_count_index());
+
         assert_eq!(
             table,
             TableCatalog {
@@ -710,6 +1133,7 @@ mod tests {
                 retention_seconds: Some(300),
                 fragment_id: 0,
                 dml_fragment_id: None,
+                schedule: None,
                 vnode_col_index: None,
                 row_id_index: None,
                 value_indices: vec![0],
@@ -720,6 +1144,9 @@ mod tests {
                 watermark_columns: FixedBitSet::with_capacity(2),
                 dist_key_in_pk: vec![],
                 cardinality: Cardinality::unknown(),
+                partition_count_index: Some(PartitionCountIndexDesc {
+                    index_table_id: TableId::new(42),
+                }),
                 created_at_epoch: None,
                 initialized_at_epoch: None,
                 cleaned_by_watermark: false,
@@ -731,8 +1158,231 @@ mod tests {
                 initialized_at_cluster_version: None,
                 dependent_relations: vec![],
                 version_column_index: None,
+                constraints: vec![TableConstraint::Unique {
+                    name: "country_key".to_string(),
+                    columns: vec![1],
+                }],
+                dictionary_encoded_column_indices: FixedBitSet::with_capacity(2),
+                column_compression_hints: vec![ColumnCompressionHint {
+                    column_index: 1,
+                    codec: ColumnCompression::Zstd { level: 5 },
+                }],
             }
         );
         assert_eq!(table, TableCatalog::from(table.to_prost(0, 0)));
+        assert_eq!(
+            table.unique_constraints().collect_vec(),
+            vec![("country_key", &[1usize][..])]
+        );
+        assert_eq!(table.check_constraints().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of range")]
+    fn test_table_catalog_rejects_out_of_range_unique_constraint() {
+        let _: TableCatalog = PbTable {
+            id: 0,
+            schema_id: 0,
+            database_id: 0,
+            name: "test".to_string(),
+            table_type: PbTableType::Table as i32,
+            columns: vec![PbColumnCatalog {
+                column_desc: Some((&row_id_column_desc()).into()),
+                is_hidden: true,
+            }],
+            pk: vec![ColumnOrder::new(0, OrderType::ascending()).to_protobuf()],
+            stream_key: vec![0],
+            dependent_relations: vec![],
+            distribution_key: vec![],
+            optional_associated_source_id: None,
+            append_only: false,
+            owner: risingwave_common::catalog::DEFAULT_SUPER_USER_ID,
+            retention_seconds: None,
+            fragment_id: 0,
+            dml_fragment_id: None,
+            schedule: None,
+            initialized_at_epoch: None,
+            value_indices: vec![0],
+            definition: "".into(),
+            read_prefix_len_hint: 0,
+            vnode_col_index: None,
+            row_id_index: None,
+            version: Some(PbTableVersion {
+                version: 0,
+                next_column_id: 1,
+                content_digest: None,
+            }),
+            watermark_indices: vec![],
+            handle_pk_conflict_behavior: 3,
+            dist_key_in_pk: vec![],
+            partition_count_index: None,
+            cardinality: None,
+            created_at_epoch: None,
+            cleaned_by_watermark: false,
+            stream_job_status: PbStreamJobStatus::Created.into(),
+            create_type: PbCreateType::Foreground.into(),
+            description: None,
+            incoming_sinks: vec![],
+            created_at_cluster_version: None,
+            initialized_at_cluster_version: None,
+            version_column_index: None,
+            constraints: vec![PbTableConstraint {
+                constraint_type: Some(PbConstraintType::Unique(PbUniqueConstraint {
+                    name: "bad".to_string(),
+                    column_indices: vec![5],
+                })),
+            }],
+            dictionary_encoded_column_indices: vec![],
+            column_compression: vec![],
+        }
+        .into();
+    }
+
+    #[test]
+    fn test_schema_fingerprint_ignores_bookkeeping_but_not_schema() {
+        let base = TableCatalog {
+            columns: vec![ColumnCatalog::row_id_column()],
+            pk: vec![ColumnOrder::new(0, OrderType::ascending())],
+            stream_key: vec![0],
+            value_indices: vec![0],
+            ..Default::default()
+        };
+
+        // Touching only a volatile bookkeeping field must not change the fingerprint.
+        let touched_epoch = TableCatalog {
+            created_at_epoch: Some(Epoch::from(123)),
+            fragment_id: 42,
+            ..base.clone()
+        };
+        assert_eq!(base.schema_fingerprint(), touched_epoch.schema_fingerprint());
+
+        // Mutating a column's data type must change the fingerprint.
+        let mut changed_type = base.clone();
+        changed_type.columns[0].column_desc.data_type = DataType::Varchar;
+        assert_ne!(base.schema_fingerprint(), changed_type.schema_fingerprint());
+    }
+
+    #[test]
+    fn test_dictionary_encoded_columns() {
+        let mut table = TableCatalog {
+            columns: vec![
+                ColumnCatalog::row_id_column(),
+                ColumnCatalog {
+                    column_desc: ColumnDesc::new_atomic(DataType::Varchar, "name", 1),
+                    is_hidden: false,
+                },
+            ],
+            pk: vec![ColumnOrder::new(0, OrderType::ascending())],
+            stream_key: vec![0],
+            value_indices: vec![0, 1],
+            ..Default::default()
+        };
+        assert_eq!(table.column_encoding(1), ColumnEncoding::Plain);
+        assert!(table.dictionary_encoded_columns().is_empty());
+
+        table.dictionary_encoded_column_indices.grow(2);
+        table.dictionary_encoded_column_indices.insert(1);
+        assert_eq!(table.column_encoding(1), ColumnEncoding::Dictionary);
+        assert_eq!(table.dictionary_encoded_columns(), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dictionary encoding is only supported on varchar/bytea columns")]
+    fn test_dictionary_encoding_rejects_unsupported_type() {
+        let _: TableCatalog = PbTable {
+            id: 0,
+            schema_id: 0,
+            database_id: 0,
+            name: "test".to_string(),
+            table_type: PbTableType::Table as i32,
+            columns: vec![PbColumnCatalog {
+                column_desc: Some((&row_id_column_desc()).into()),
+                is_hidden: true,
+            }],
+            pk: vec![ColumnOrder::new(0, OrderType::ascending()).to_protobuf()],
+            stream_key: vec![0],
+            dependent_relations: vec![],
+            distribution_key: vec![],
+            optional_associated_source_id: None,
+            append_only: false,
+            owner: risingwave_common::catalog::DEFAULT_SUPER_USER_ID,
+            retention_seconds: None,
+            fragment_id: 0,
+            dml_fragment_id: None,
+            schedule: None,
+            initialized_at_epoch: None,
+            value_indices: vec![0],
+            definition: "".into(),
+            read_prefix_len_hint: 0,
+            vnode_col_index: None,
+            row_id_index: None,
+            version: Some(PbTableVersion {
+                version: 0,
+                next_column_id: 1,
+                content_digest: None,
+            }),
+            watermark_indices: vec![],
+            handle_pk_conflict_behavior: 3,
+            dist_key_in_pk: vec![],
+            partition_count_index: None,
+            cardinality: None,
+            created_at_epoch: None,
+            cleaned_by_watermark: false,
+            stream_job_status: PbStreamJobStatus::Created.into(),
+            create_type: PbCreateType::Foreground.into(),
+            description: None,
+            incoming_sinks: vec![],
+            created_at_cluster_version: None,
+            initialized_at_cluster_version: None,
+            version_column_index: None,
+            constraints: vec![],
+            // Column 0 is the row id column (`Serial`), not `varchar`/`bytea`.
+            dictionary_encoded_column_indices: vec![0],
+            column_compression: vec![],
+        }
+        .into();
+    }
+
+    #[test]
+    fn test_scheduled_table_round_trip() {
+        let mut table = TableCatalog {
+            table_type: TableType::Scheduled,
+            schedule: Some(TableSchedule {
+                every_seconds: 3600,
+                at_offset: Some(120),
+            }),
+            ..Default::default()
+        };
+        assert!(table.is_scheduled());
+        assert!(!table.is_table());
+
+        table = TableCatalog::from(table.to_prost(0, 0));
+        assert!(table.is_scheduled());
+        assert_eq!(
+            table.schedule,
+            Some(TableSchedule {
+                every_seconds: 3600,
+                at_offset: Some(120),
+            })
+        );
+    }
+
+    #[test]
+    fn test_content_digest_round_trip() {
+        let mut table = TableCatalog {
+            version: Some(TableVersion {
+                version_id: 0,
+                next_column_id: ColumnId::new(1),
+                content_digest: Some(MerkleTree::build(vec![1, 2, 3]).root()),
+            }),
+            ..Default::default()
+        };
+        assert!(table.content_digest().is_some());
+
+        table = TableCatalog::from(table.to_prost(0, 0));
+        assert_eq!(
+            table.content_digest(),
+            Some(MerkleTree::build(vec![1, 2, 3]).root())
+        );
     }
 }
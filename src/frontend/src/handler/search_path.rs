@@ -0,0 +1,105 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolution logic shared by the `current_schema()` / `current_schemas(include_implicit)`
+//! scalar functions and `SHOW search_path`. The functions themselves are registered in the
+//! expression/catalog function registry (not part of this crate); this module only owns the pure
+//! parsing and resolution, so `SHOW search_path` is guaranteed to parse the setting exactly the
+//! way those functions do.
+
+use std::collections::HashSet;
+
+/// The implicit schemas every search path resolves against first, regardless of its own
+/// contents -- mirrors Postgres always searching `pg_catalog`.
+pub const IMPLICIT_SCHEMAS: &[&str] = &["pg_catalog"];
+
+/// Parses the comma-separated `search_path` setting into an ordered list of schema names, with
+/// surrounding whitespace trimmed from each entry and empty entries dropped.
+pub fn parse_search_path(search_path: &str) -> Vec<String> {
+    search_path
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Resolves `current_schemas(include_implicit)`: the ordered, deduplicated list of schemas on
+/// `search_path` for which `resolvable` returns `true`, with [`IMPLICIT_SCHEMAS`] prepended when
+/// `include_implicit` is set.
+pub fn current_schemas(
+    search_path: &str,
+    include_implicit: bool,
+    resolvable: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let implicit: &[&str] = if include_implicit { IMPLICIT_SCHEMAS } else { &[] };
+
+    let mut seen = HashSet::new();
+    implicit
+        .iter()
+        .map(|s| s.to_string())
+        .chain(parse_search_path(search_path))
+        .filter(|schema| resolvable(schema))
+        .filter(|schema| seen.insert(schema.clone()))
+        .collect()
+}
+
+/// Resolves `current_schema()`: the first schema on `search_path` that `resolvable` accepts, or
+/// `None` if none do (Postgres returns SQL `NULL` in that case).
+pub fn current_schema(search_path: &str, resolvable: impl Fn(&str) -> bool) -> Option<String> {
+    current_schemas(search_path, false, resolvable).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_search_path_trims_and_drops_empty_entries() {
+        assert_eq!(
+            parse_search_path(" a, b ,, c"),
+            vec!["a".to_string(), "b".to_string(), "c".to_string()]
+        );
+    }
+
+    #[test]
+    fn current_schema_returns_first_resolvable_entry() {
+        let resolvable = |s: &str| s == "b" || s == "c";
+        assert_eq!(current_schema("a, b, c", resolvable), Some("b".to_string()));
+    }
+
+    #[test]
+    fn current_schema_is_none_when_nothing_resolves() {
+        let resolvable = |_: &str| false;
+        assert_eq!(current_schema("a, b", resolvable), None);
+    }
+
+    #[test]
+    fn current_schemas_prepends_implicit_and_dedupes() {
+        let resolvable = |s: &str| matches!(s, "pg_catalog" | "public");
+        assert_eq!(
+            current_schemas("public, pg_catalog", true, resolvable),
+            vec!["pg_catalog".to_string(), "public".to_string()]
+        );
+    }
+
+    #[test]
+    fn current_schemas_without_implicit_only_uses_search_path() {
+        let resolvable = |s: &str| matches!(s, "pg_catalog" | "public");
+        assert_eq!(
+            current_schemas("public", false, resolvable),
+            vec!["public".to_string()]
+        );
+    }
+}
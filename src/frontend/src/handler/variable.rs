@@ -24,10 +24,25 @@ use risingwave_common::types::{DataType, ScalarRefImpl};
 use risingwave_sqlparser::ast::{Ident, SetTimeZoneValue, SetVariableValue, Value};
 use risingwave_sqlparser::keywords::Keyword;
 
+use super::search_path;
+use super::variable_vars;
 use super::RwPgResponse;
 use crate::handler::HandlerArgs;
 use crate::utils::infer_stmt_row_desc::infer_show_variable;
 
+/// The scope a `SET` applies to. Threaded down from the parser's `SET [SESSION | LOCAL]` clause;
+/// bare `SET` (no keyword) is [`SetVariableScope::Session`], matching Postgres.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetVariableScope {
+    /// Applies to the whole session, until changed again or the connection closes.
+    Session,
+    /// Applies only to the current transaction: installed into a transaction-local overlay that
+    /// `SHOW`/`config().get` consult before falling back to the session value, and discarded on
+    /// `COMMIT`/`ROLLBACK` so the override never leaks into a later transaction on the same
+    /// connection. A no-op outside an explicit transaction, same as Postgres.
+    Local,
+}
+
 /// convert `SetVariableValue` to string while remove the quotes on literals.
 pub(crate) fn set_var_to_param_str(value: &SetVariableValue) -> Option<String> {
     match value {
@@ -41,27 +56,44 @@ pub(crate) fn set_var_to_param_str(value: &SetVariableValue) -> Option<String> {
     }
 }
 
-pub fn handle_set(
-    handler_args: HandlerArgs,
-    name: Ident,
-    value: SetVariableValue,
-) -> Result<RwPgResponse> {
-    // Strip double and single quotes
-    let mut string_val = set_var_to_param_str(&value);
-
-    let mut status = ParameterStatus::default();
-
-    struct Reporter<'a> {
-        status: &'a mut ParameterStatus,
-    }
+struct Reporter<'a> {
+    status: &'a mut ParameterStatus,
+}
 
-    impl<'a> ConfigReporter for Reporter<'a> {
-        fn report_status(&mut self, key: &str, new_val: String) {
-            if key == "APPLICATION_NAME" {
-                self.status.application_name = Some(new_val);
+impl<'a> ConfigReporter for Reporter<'a> {
+    /// Pushes a `ParameterStatus` for any of Postgres's `GUC_REPORT` variables -- the ones the
+    /// server is expected to report on every change so a driver's local session state (notably
+    /// `timezone` and `client_encoding`) stays in sync without an explicit `SHOW` round-trip.
+    /// Any other key is a no-op: the client doesn't need to hear about it.
+    fn report_status(&mut self, key: &str, new_val: String) {
+        match key.to_ascii_lowercase().as_str() {
+            "application_name" => self.status.application_name = Some(new_val),
+            "server_version" => self.status.server_version = Some(new_val),
+            "client_encoding" => self.status.client_encoding = Some(new_val),
+            "datestyle" => self.status.date_style = Some(new_val),
+            "timezone" => self.status.timezone = Some(new_val),
+            "integer_datetimes" => self.status.integer_datetimes = Some(new_val),
+            "standard_conforming_strings" => {
+                self.status.standard_conforming_strings = Some(new_val)
             }
+            "server_encoding" => self.status.server_encoding = Some(new_val),
+            _ => {}
         }
     }
+}
+
+/// Core of `handle_set`/`handle_reset`: validates `value` against the declared type (if any),
+/// then applies it to `name` at the given `scope`, merging any reported parameter changes into
+/// `status`.
+fn apply_set(
+    handler_args: &HandlerArgs,
+    name: &Ident,
+    value: SetVariableValue,
+    scope: SetVariableScope,
+    status: &mut ParameterStatus,
+) -> Result<()> {
+    // Strip double and single quotes
+    let mut string_val = set_var_to_param_str(&value);
 
     // special handle for streaming parallelism,
     if name
@@ -75,16 +107,86 @@ pub fn handle_set(
         string_val = None;
     }
 
-    // Currently store the config variable simply as String -> ConfigEntry(String).
-    // In future we can add converter/parser to make the API more robust.
+    // Parse and validate against the declared type before the value ever reaches session
+    // storage, so a bad `SET` fails immediately instead of only once something downstream tries
+    // to use it. Variables not declared in `variable_vars` still fall back to the untyped
+    // String -> ConfigEntry(String) path below, unvalidated.
     // We remark that the name of session parameter is always case-insensitive.
-    handler_args.session.set_config_report(
-        &name.real_value().to_lowercase(),
-        string_val,
-        Reporter {
-            status: &mut status,
-        },
-    )?;
+    if let (Some(desc), Some(raw)) = (
+        variable_vars::lookup(&name.real_value()),
+        string_val.as_deref(),
+    ) {
+        variable_vars::parse_and_validate(desc, raw)?;
+    }
+
+    let var_name = name.real_value().to_lowercase();
+    match scope {
+        SetVariableScope::Session => {
+            handler_args
+                .session
+                .set_config_report(&var_name, string_val, Reporter { status })?;
+        }
+        SetVariableScope::Local => {
+            // Same validation and reporting as `SESSION`, but installed into the transaction-local
+            // overlay instead of the session's own config, so it's discarded on COMMIT/ROLLBACK.
+            handler_args
+                .session
+                .set_config_report_local(&var_name, string_val, Reporter { status })?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_set(
+    handler_args: HandlerArgs,
+    name: Ident,
+    value: SetVariableValue,
+    scope: SetVariableScope,
+) -> Result<RwPgResponse> {
+    let mut status = ParameterStatus::default();
+    apply_set(&handler_args, &name, value, scope, &mut status)?;
+
+    Ok(PgResponse::builder(StatementType::SET_VARIABLE)
+        .status(status)
+        .into())
+}
+
+/// Handles `RESET <var>` / `RESET ALL`, restoring one variable (or all of them) to its
+/// registered default by routing through [`apply_set`] with [`SetVariableValue::Default`], the
+/// same path `SET <var> TO DEFAULT` uses.
+pub fn handle_reset(handler_args: HandlerArgs, name: Option<Ident>) -> Result<RwPgResponse> {
+    let mut status = ParameterStatus::default();
+
+    match name {
+        Some(name) => {
+            apply_set(
+                &handler_args,
+                &name,
+                SetVariableValue::Default,
+                SetVariableScope::Session,
+                &mut status,
+            )?;
+        }
+        None => {
+            let names = handler_args
+                .session
+                .config()
+                .show_all()
+                .iter()
+                .map(|info| info.name.clone())
+                .collect_vec();
+            for name in names {
+                apply_set(
+                    &handler_args,
+                    &Ident::new_unchecked(name),
+                    SetVariableValue::Default,
+                    SetVariableScope::Session,
+                    &mut status,
+                )?;
+            }
+        }
+    }
 
     Ok(PgResponse::builder(StatementType::SET_VARIABLE)
         .status(status)
@@ -106,22 +208,75 @@ pub(super) fn handle_set_time_zone(
         _ => Ok(value.to_string()),
     }?;
 
-    handler_args.session.set_config("timezone", tz_info)?;
+    // `timezone` is a GUC_REPORT variable, so report it back to the client the same way `SET
+    // timezone = ...` would, instead of silently updating session state.
+    let mut status = ParameterStatus::default();
+    handler_args.session.set_config_report(
+        "timezone",
+        Some(tz_info),
+        Reporter {
+            status: &mut status,
+        },
+    )?;
 
-    Ok(PgResponse::empty_result(StatementType::SET_VARIABLE))
+    Ok(PgResponse::builder(StatementType::SET_VARIABLE)
+        .status(status)
+        .into())
+}
+
+/// A `SHOW ... LIKE 'pattern'` / `SHOW ... ILIKE 'pattern'` filter, applied to variable names
+/// before they're returned by `handle_show_all`/`handle_show_system_params`.
+#[derive(Clone, Debug)]
+pub enum ShowVariableFilter {
+    Like(String),
+    ILike(String),
+}
+
+impl ShowVariableFilter {
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            ShowVariableFilter::Like(pattern) => sql_like_matches(name, pattern, false),
+            ShowVariableFilter::ILike(pattern) => sql_like_matches(name, pattern, true),
+        }
+    }
+}
+
+fn sql_like_matches(value: &str, pattern: &str, case_insensitive: bool) -> bool {
+    let normalize = |s: &str| if case_insensitive { s.to_lowercase() } else { s.to_owned() };
+    like_match(normalize(value).as_bytes(), normalize(pattern).as_bytes())
+}
+
+/// SQL `LIKE` matching: `%` matches any run of characters (including none), `_` matches exactly
+/// one, anything else must match literally.
+fn like_match(value: &[u8], pattern: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => value.is_empty(),
+        Some((b'%', rest)) => {
+            like_match(value, rest) || (!value.is_empty() && like_match(&value[1..], pattern))
+        }
+        Some((b'_', rest)) => !value.is_empty() && like_match(&value[1..], rest),
+        Some((c, rest)) => value.first() == Some(c) && like_match(&value[1..], rest),
+    }
 }
 
 pub(super) async fn handle_show(
     handler_args: HandlerArgs,
     variable: Vec<Ident>,
+    filter: Option<ShowVariableFilter>,
 ) -> Result<RwPgResponse> {
     // TODO: Verify that the name used in `show` command is indeed always case-insensitive.
     let name = variable.iter().map(|e| e.real_value()).join(" ");
     let row_desc = infer_show_variable(&name);
     let rows = if name.eq_ignore_ascii_case("PARAMETERS") {
-        handle_show_system_params(handler_args).await?
+        handle_show_system_params(handler_args, filter).await?
     } else if name.eq_ignore_ascii_case("ALL") {
-        handle_show_all(handler_args.clone())?
+        handle_show_all(handler_args.clone(), filter)?
+    } else if name.eq_ignore_ascii_case("search_path") {
+        // Normalized through the same parsing `current_schema()`/`current_schemas()` use, so
+        // `SHOW search_path` always reflects exactly what those functions resolve against.
+        let config_reader = handler_args.session.config();
+        let normalized = search_path::parse_search_path(&config_reader.get(&name)?).join(", ");
+        vec![Row::new(vec![Some(normalized.into())])]
     } else {
         let config_reader = handler_args.session.config();
         vec![Row::new(vec![Some(config_reader.get(&name)?.into())])]
@@ -132,25 +287,44 @@ pub(super) async fn handle_show(
         .into())
 }
 
-fn handle_show_all(handler_args: HandlerArgs) -> Result<Vec<Row>> {
+fn handle_show_all(
+    handler_args: HandlerArgs,
+    filter: Option<ShowVariableFilter>,
+) -> Result<Vec<Row>> {
     let config_reader = handler_args.session.config();
 
     let all_variables = config_reader.show_all();
 
     let rows = all_variables
         .iter()
+        .filter(|info| filter.as_ref().map_or(true, |f| f.matches(&info.name)))
         .map(|info| {
+            // The declared type, e.g. "integer" or "enum", falling back to "string" for
+            // variables not (yet) declared in `variable_vars`. The typed default is folded into
+            // the description rather than added as its own column, since the row shape here must
+            // stay in lockstep with `infer_show_variable`'s row descriptor.
+            let var_type = variable_vars::display_type(&info.name);
+            let description = match variable_vars::lookup(&info.name) {
+                Some(desc) => format!(
+                    "{} (type: {var_type}, default: {})",
+                    info.description, desc.default
+                ),
+                None => info.description.clone(),
+            };
             Row::new(vec![
                 Some(info.name.clone().into()),
                 Some(info.setting.clone().into()),
-                Some(info.description.clone().into()),
+                Some(description.into()),
             ])
         })
         .collect_vec();
     Ok(rows)
 }
 
-async fn handle_show_system_params(handler_args: HandlerArgs) -> Result<Vec<Row>> {
+async fn handle_show_system_params(
+    handler_args: HandlerArgs,
+    filter: Option<ShowVariableFilter>,
+) -> Result<Vec<Row>> {
     let params = handler_args
         .session
         .env()
@@ -160,6 +334,7 @@ async fn handle_show_system_params(handler_args: HandlerArgs) -> Result<Vec<Row>
     let rows = params
         .to_kv()
         .into_iter()
+        .filter(|(k, _)| filter.as_ref().map_or(true, |f| f.matches(k)))
         .map(|(k, v)| {
             let is_mutable_bytes = ScalarRefImpl::Bool(is_mutable(&k).unwrap())
                 .text_format(&DataType::Boolean)